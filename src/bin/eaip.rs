@@ -0,0 +1,455 @@
+use airac::AIRAC;
+use anyhow::{anyhow, bail, Context};
+use chrono::{Datelike, NaiveDate};
+use clap::{Parser as _, Subcommand, ValueEnum};
+use eaip::diff::Diffable;
+use eaip::eaip::ais;
+use eaip::export::geojson::FeatureCollection;
+use eaip::prelude::*;
+use serde::Serialize;
+
+/// Fetch and export data from electronic aeronautical information packages (eAIPs).
+///
+/// Do not use for real world flight.
+#[derive(Debug, clap::Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The country code of the AIS to fetch from, such as GB or NL.
+    #[arg(short, long, global = true, default_value = "GB")]
+    ais: String,
+
+    /// The AIRAC to fetch: `current`, `next`, `latest` (the next AIRAC if it has been published,
+    /// otherwise the current one), a cycle such as `2205`, or a date such as `2022-05-19`.
+    #[arg(short = 'c', long, global = true, default_value = "current")]
+    airac: String,
+
+    /// The output format.
+    #[arg(short, long, global = true, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+    Geojson,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// List the AISs that can be selected with `--ais`.
+    Ais,
+    /// List radio navaids.
+    Navaids,
+    /// List intersections.
+    Fixes,
+    /// List airways and their waypoints.
+    Airways,
+    /// List airports, by ICAO code and name.
+    Airports,
+    /// Show the details of an airport.
+    Airport {
+        /// The ICAO code of the airport.
+        icao: String,
+    },
+    /// List the charts of an airport.
+    Charts {
+        /// The ICAO code of the airport.
+        icao: String,
+    },
+    /// List the AIRAC cycles published by the AIS.
+    Cycles,
+    /// Compare the navigation data of two AIRACs. GeoJSON output is not supported.
+    Diff {
+        /// The older AIRAC, in any form accepted by `--airac`. Defaults to `--airac`.
+        #[arg(long)]
+        from: Option<String>,
+        /// The newer AIRAC, in any form accepted by `--airac`. Defaults to the AIRAC after
+        /// `--from`.
+        #[arg(long)]
+        to: Option<String>,
+    },
+}
+
+/// Rows of data, for table and CSV output.
+#[derive(Debug, Default)]
+struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    fn new(headers: &[&'static str]) -> Self {
+        Self {
+            headers: headers.to_vec(),
+            rows: Vec::new(),
+        }
+    }
+
+    fn push<I: IntoIterator<Item = String>>(&mut self, row: I) {
+        self.rows.push(row.into_iter().collect());
+    }
+
+    /// Format as columns aligned with spaces.
+    fn to_table(&self) -> String {
+        let mut widths = self.headers.iter().map(|h| h.len()).collect::<Vec<_>>();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        let headers = self.headers.iter().map(|h| h.to_string()).collect();
+        let mut out = String::new();
+        for row in std::iter::once(&headers).chain(&self.rows) {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ");
+            out += line.trim_end();
+            out += "\n";
+        }
+        out
+    }
+
+    /// Format as CSV, with a header row.
+    fn to_csv(&self) -> String {
+        let headers = self.headers.iter().map(|h| h.to_string()).collect();
+        let mut out = String::new();
+        for row in std::iter::once(&headers).chain(&self.rows) {
+            out += &row
+                .iter()
+                .map(|c| csv_field(c))
+                .collect::<Vec<_>>()
+                .join(",");
+            out += "\n";
+        }
+        out
+    }
+}
+
+/// Quote a CSV field if needed.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Parse an AIRAC given as a cycle, such as `2205`, or a date, such as `2022-05-19`.
+fn parse_airac(s: &str) -> anyhow::Result<AIRAC> {
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(AIRAC::from_ymd(date.year(), date.month(), date.day()));
+    }
+    if s.len() == 4 && s.chars().all(|c| c.is_ascii_digit()) {
+        let year = 2000 + s[..2].parse::<i32>()?;
+        let mut airac = AIRAC::from_ymd(year, 1, 1);
+        while airac.starts().year() <= year {
+            if airac.to_string() == s {
+                return Ok(airac);
+            }
+            airac = airac.next();
+        }
+    }
+    bail!(
+        "Invalid AIRAC {:?}, expected a cycle such as 2205 or a date such as 2022-05-19",
+        s
+    )
+}
+
+/// Resolve an AIRAC given on the command line.
+async fn resolve_airac(eaip: &EAIP, s: &str) -> anyhow::Result<AIRAC> {
+    match s {
+        "current" => Ok(AIRAC::current()),
+        "next" => Ok(AIRAC::current().next()),
+        "latest" => Ok(eaip.latest_published_airac().await?),
+        s => parse_airac(s),
+    }
+}
+
+/// Format data as requested. `geojson` is `None` if GeoJSON isn't supported for this data.
+fn output<T: Serialize>(
+    format: Format,
+    data: &T,
+    table: Table,
+    geojson: Option<FeatureCollection>,
+) -> anyhow::Result<String> {
+    match format {
+        Format::Table => Ok(table.to_table()),
+        Format::Csv => Ok(table.to_csv()),
+        Format::Json => Ok(serde_json::to_string_pretty(data)? + "\n"),
+        Format::Geojson => geojson
+            .map(|g| g.to_string() + "\n")
+            .ok_or_else(|| anyhow!("GeoJSON output is not supported for this command")),
+    }
+}
+
+fn navaids_table(navaids: &[NavAid]) -> Table {
+    let mut table = Table::new(&[
+        "ID",
+        "Name",
+        "Kind",
+        "Frequency",
+        "Latitude",
+        "Longitude",
+        "Elevation",
+    ]);
+    for navaid in navaids {
+        let frequency = if matches!(navaid.kind(), NavAidKind::NDB | NavAidKind::Locator) {
+            format!("{} kHz", navaid.frequency_khz())
+        } else {
+            format!("{:.3} MHz", navaid.frequency())
+        };
+        table.push([
+            navaid.id().clone(),
+            navaid.name().clone(),
+            format!("{:?}", navaid.kind()),
+            frequency,
+            navaid.latitude().to_string(),
+            navaid.longitude().to_string(),
+            navaid.elevation().to_string(),
+        ]);
+    }
+    table
+}
+
+fn diff_rows<T: Diffable>(table: &mut Table, diff: &Diff<T>) {
+    for item in diff.added() {
+        table.push([
+            T::NAME.to_string(),
+            item.key(),
+            "added".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+        ]);
+    }
+    for item in diff.removed() {
+        table.push([
+            T::NAME.to_string(),
+            item.key(),
+            "removed".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+        ]);
+    }
+    for modified in diff.modified() {
+        for change in modified.changes() {
+            table.push([
+                T::NAME.to_string(),
+                modified.new_value().key(),
+                "modified".to_string(),
+                change.field().clone(),
+                change.old().clone(),
+                change.new_value().clone(),
+            ]);
+        }
+    }
+}
+
+/// Run a command, returning the output.
+async fn run(cli: &Cli, eaip: &EAIP) -> anyhow::Result<String> {
+    match &cli.command {
+        Command::Ais => {
+            let mut table = Table::new(&["Country", "Name", "ICAO Prefix", "URL"]);
+            for named in ais::ALL.iter() {
+                table.push([
+                    named.country().to_string(),
+                    named.name().to_string(),
+                    named.icao_prefix().to_string(),
+                    named.url().to_string(),
+                ]);
+            }
+            let data = ais::ALL
+                .iter()
+                .map(|named| named.country())
+                .collect::<Vec<_>>();
+            output(cli.format, &data, table, None)
+        }
+        Command::Navaids => {
+            let airac = resolve_airac(eaip, &cli.airac).await?;
+            let navaids = Navaids::from_eaip(eaip, airac).await?;
+            let mut geojson = FeatureCollection::new();
+            geojson.add_navaids(&navaids);
+            output(cli.format, &navaids, navaids_table(&navaids), Some(geojson))
+        }
+        Command::Fixes => {
+            let airac = resolve_airac(eaip, &cli.airac).await?;
+            let intersections = Intersections::from_eaip(eaip, airac).await?;
+            let mut table = Table::new(&["Designator", "Latitude", "Longitude"]);
+            for intersection in &intersections {
+                table.push([
+                    intersection.designator().clone(),
+                    intersection.latitude().to_string(),
+                    intersection.longitude().to_string(),
+                ]);
+            }
+            let mut geojson = FeatureCollection::new();
+            geojson.add_intersections(&intersections);
+            output(cli.format, &intersections, table, Some(geojson))
+        }
+        Command::Airways => {
+            let airac = resolve_airac(eaip, &cli.airac).await?;
+            let airways = Airways::from_eaip(eaip, airac.clone()).await?;
+            let mut table = Table::new(&["Airway", "Waypoint", "Lower Limit", "Upper Limit"]);
+            for airway in &airways {
+                for waypoint in airway.waypoints() {
+                    table.push([
+                        airway.designator().clone(),
+                        waypoint.designator().clone(),
+                        waypoint.lower_limit().clone(),
+                        waypoint.upper_limit().clone(),
+                    ]);
+                }
+            }
+            let geojson = if cli.format == Format::Geojson {
+                let navaids = Navaids::from_eaip(eaip, airac.clone()).await?;
+                let intersections = Intersections::from_eaip(eaip, airac).await?;
+                let mut geojson = FeatureCollection::new();
+                geojson.add_airways(&airways, &navaids, &intersections);
+                Some(geojson)
+            } else {
+                None
+            };
+            output(cli.format, &airways, table, geojson)
+        }
+        Command::Airports => {
+            let airac = resolve_airac(eaip, &cli.airac).await?;
+            let airports = Airports::from_eaip(eaip, airac).await?;
+            let mut table = Table::new(&["ICAO", "Name"]);
+            for airport in &airports {
+                table.push([airport.icao().clone(), airport.name().clone()]);
+            }
+            // The list of airports has no positions, so can't be GeoJSON.
+            output(cli.format, &airports, table, None)
+        }
+        Command::Airport { icao } => {
+            let airac = resolve_airac(eaip, &cli.airac).await?;
+            let airport = Airport::from_eaip(eaip, airac, icao.to_uppercase())
+                .await
+                .with_context(|| format!("Failed to get airport {}", icao))?;
+            let mut table = Table::new(&["Field", "Value"]);
+            for (field, value) in [
+                ("ICAO", airport.icao().clone()),
+                ("Name", airport.name().clone()),
+                ("Latitude", airport.latitude().to_string()),
+                ("Longitude", airport.longitude().to_string()),
+                ("Elevation", format!("{} ft", airport.elevation())),
+                (
+                    "Runways",
+                    airport
+                        .runways()
+                        .iter()
+                        .map(|r| r.designator().clone())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+                ("Charts", airport.charts().len().to_string()),
+            ] {
+                table.push([field.to_string(), value]);
+            }
+            let mut geojson = FeatureCollection::new();
+            geojson.add_airports(std::slice::from_ref(&airport));
+            output(cli.format, &airport, table, Some(geojson))
+        }
+        Command::Charts { icao } => {
+            let airac = resolve_airac(eaip, &cli.airac).await?;
+            let airport = Airport::from_eaip(eaip, airac, icao.to_uppercase())
+                .await
+                .with_context(|| format!("Failed to get airport {}", icao))?;
+            let mut table = Table::new(&["Title", "URL"]);
+            for chart in airport.charts() {
+                table.push([chart.title().clone(), chart.url().clone()]);
+            }
+            output(cli.format, airport.charts(), table, None)
+        }
+        Command::Cycles => {
+            let cycles = eaip.get_cycles().await?;
+            let mut table = Table::new(&["AIRAC", "Effective", "Published", "Status"]);
+            for cycle in &cycles {
+                table.push([
+                    cycle.airac().to_string(),
+                    cycle.effective().to_string(),
+                    cycle.published().map(|d| d.to_string()).unwrap_or_default(),
+                    format!("{:?}", cycle.status()),
+                ]);
+            }
+            output(cli.format, &cycles, table, None)
+        }
+        Command::Diff { from, to } => {
+            let from = resolve_airac(eaip, from.as_deref().unwrap_or(&cli.airac)).await?;
+            let to = match to {
+                Some(to) => resolve_airac(eaip, to).await?,
+                None => from.next(),
+            };
+            let old = Snapshot::from_eaip(eaip, from.clone())
+                .await
+                .with_context(|| format!("Failed to get data for AIRAC {}", from))?;
+            let new = Snapshot::from_eaip(eaip, to.clone())
+                .await
+                .with_context(|| format!("Failed to get data for AIRAC {}", to))?;
+            let diff = old.diff(&new);
+            if cli.format == Format::Table {
+                return Ok(diff.to_string());
+            }
+            let mut table = Table::new(&["Type", "Key", "Change", "Field", "Old", "New"]);
+            diff_rows(&mut table, diff.navaids());
+            diff_rows(&mut table, diff.intersections());
+            diff_rows(&mut table, diff.airways());
+            diff_rows(&mut table, diff.airports());
+            output(cli.format, &diff, table, None)
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    pretty_env_logger::init();
+    let cli = Cli::parse();
+    let named = ais::ALL
+        .iter()
+        .find(|named| named.country().eq_ignore_ascii_case(&cli.ais))
+        .ok_or_else(|| {
+            let countries = ais::ALL
+                .iter()
+                .map(|named| named.country())
+                .collect::<Vec<_>>();
+            anyhow!(
+                "Unknown AIS {:?}, expected one of: {}",
+                cli.ais,
+                countries.join(", ")
+            )
+        })?;
+    print!("{}", run(&cli, named.eaip()).await?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_airac() {
+        assert_eq!("2205", parse_airac("2205").unwrap().to_string());
+        assert_eq!("2113", parse_airac("2113").unwrap().to_string());
+        assert_eq!("2201", parse_airac("2201").unwrap().to_string());
+        assert_eq!("2205", parse_airac("2022-06-01").unwrap().to_string());
+        assert!(parse_airac("2215").is_err());
+        assert!(parse_airac("soon").is_err());
+    }
+
+    #[test]
+    fn test_table() {
+        let mut table = Table::new(&["ID", "Name"]);
+        table.push(["ADN".to_string(), "ABERDEEN, \"DYCE\"".to_string()]);
+        assert_eq!("ID   Name\nADN  ABERDEEN, \"DYCE\"\n", table.to_table());
+        assert_eq!("ID,Name\nADN,\"ABERDEEN, \"\"DYCE\"\"\"\n", table.to_csv());
+    }
+}
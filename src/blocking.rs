@@ -0,0 +1,118 @@
+//! Synchronous versions of the functions in this crate that fetch data from an eAIP, for use
+//! without an async runtime.
+//!
+//! These run the async versions to completion on a runtime shared by the whole process, so
+//! they must not be called from within an async context.
+//!
+//! ```no_run
+//! # fn main() -> eaip::error::Result<()> {
+//! use eaip::blocking::FromEAIP;
+//! use eaip::eaip::ais::GB;
+//! use eaip::parse::navaids::Navaids;
+//!
+//! let navaids = Navaids::from_current_eaip(GB.eaip())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use airac::AIRAC;
+use lazy_static::lazy_static;
+use tokio::runtime::Runtime;
+
+use crate::eaip::EAIP;
+use crate::error::Result;
+use crate::parse;
+use crate::parse::cycles::Cycles;
+use crate::parts::*;
+use crate::types::{Airport, CycleData};
+
+lazy_static! {
+    static ref RUNTIME: Runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("eaip-blocking")
+        .enable_all()
+        .build()
+        .expect("failed to start the runtime for blocking eAIP requests");
+}
+
+/// Run a future to completion on the shared runtime.
+fn block_on<F: Future>(future: F) -> F::Output {
+    RUNTIME.block_on(future)
+}
+
+/// Fetch and parse some data from an eAIP, blocking the current thread. This is implemented
+/// for everything that implements the async [`FromEAIP`](parse::FromEAIP).
+///
+/// As the methods share names with the async trait, only one of the two should be imported.
+pub trait FromEAIP {
+    /// The type this parser will output when successful
+    type Output;
+
+    /// Fetch the data from the given eAIP for the given AIRAC.
+    fn from_eaip(eaip: &EAIP, airac: AIRAC) -> Result<Self::Output>;
+
+    /// Fetch the data from the given eAIP for the current AIRAC.
+    fn from_current_eaip(eaip: &EAIP) -> Result<Self::Output> {
+        Self::from_eaip(eaip, AIRAC::current())
+    }
+
+    /// Fetch the data from the given eAIP for the next AIRAC if it has been published, otherwise
+    /// for the current AIRAC.
+    fn from_latest_eaip(eaip: &EAIP) -> Result<CycleData<Self::Output>>;
+}
+
+impl<T: parse::FromEAIP + Send + Sync> FromEAIP for T {
+    type Output = T::Output;
+
+    fn from_eaip(eaip: &EAIP, airac: AIRAC) -> Result<Self::Output> {
+        block_on(<T as parse::FromEAIP>::from_eaip(eaip, airac))
+    }
+
+    fn from_latest_eaip(eaip: &EAIP) -> Result<CycleData<Self::Output>> {
+        block_on(<T as parse::FromEAIP>::from_latest_eaip(eaip))
+    }
+}
+
+/// Get a page from an eAIP for the specified AIRAC.
+pub fn get_page(eaip: &EAIP, airac: AIRAC, part: Part, typ: EAIPType) -> Result<String> {
+    block_on(eaip.get_page(airac, part, typ))
+}
+
+/// Get a page from an eAIP for the current AIRAC.
+pub fn get_current_page(eaip: &EAIP, part: Part, typ: EAIPType) -> Result<String> {
+    block_on(eaip.get_current_page(part, typ))
+}
+
+/// Get any file from an eAIP by its location relative to the base URI.
+pub fn get_file(eaip: &EAIP, location: &str) -> Result<Vec<u8>> {
+    block_on(eaip.get_file(location))
+}
+
+/// Get the AIRAC cycles an eAIP has published, from its history page.
+pub fn get_cycles(eaip: &EAIP) -> Result<Cycles> {
+    block_on(eaip.get_cycles())
+}
+
+/// Fetch the data for an airport from the given eAIP for the given AIRAC.
+pub fn airport_from_eaip(eaip: &EAIP, airac: AIRAC, aerodrome: String) -> Result<Airport> {
+    block_on(Airport::from_eaip(eaip, airac, aerodrome))
+}
+
+/// Fetch the data for an airport from the given eAIP for the current AIRAC.
+pub fn airport_from_current_eaip(eaip: &EAIP, aerodrome: String) -> Result<Airport> {
+    block_on(Airport::from_current_eaip(eaip, aerodrome))
+}
+
+/// Fetch the data for an airport from the given eAIP for the next AIRAC if it has been
+/// published, otherwise for the current AIRAC.
+pub fn airport_from_latest_eaip(eaip: &EAIP, aerodrome: String) -> Result<CycleData<Airport>> {
+    block_on(Airport::from_latest_eaip(eaip, aerodrome))
+}
+
+/// Get the next AIRAC if its package has been published in an eAIP, otherwise the current
+/// AIRAC.
+pub fn latest_published_airac(eaip: &EAIP) -> Result<AIRAC> {
+    block_on(eaip.latest_published_airac())
+}
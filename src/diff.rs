@@ -0,0 +1,806 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use airac::AIRAC;
+use async_trait::async_trait;
+
+use crate::prelude::*;
+
+/// A change to a single field of an item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldChange {
+    pub(crate) field: String,
+    pub(crate) old: String,
+    pub(crate) new: String,
+}
+
+impl FieldChange {
+    fn new<F: Into<String>, V: Display>(field: F, old: V, new: V) -> Self {
+        Self {
+            field: field.into(),
+            old: old.to_string(),
+            new: new.to_string(),
+        }
+    }
+
+    /// The name of the field that changed.
+    pub fn field(&self) -> &String {
+        &self.field
+    }
+
+    /// The old value of the field.
+    pub fn old(&self) -> &String {
+        &self.old
+    }
+
+    /// The new value of the field.
+    pub fn new_value(&self) -> &String {
+        &self.new
+    }
+}
+
+/// An item that exists in both the old and new data, but has changed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Modified<T> {
+    pub(crate) old: T,
+    pub(crate) new: T,
+    pub(crate) changes: Vec<FieldChange>,
+}
+
+impl<T> Modified<T> {
+    /// The item in the old data.
+    pub fn old(&self) -> &T {
+        &self.old
+    }
+
+    /// The item in the new data.
+    pub fn new_value(&self) -> &T {
+        &self.new
+    }
+
+    /// The fields that changed.
+    pub fn changes(&self) -> &Vec<FieldChange> {
+        &self.changes
+    }
+}
+
+/// The differences between two lists of items.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diff<T> {
+    pub(crate) added: Vec<T>,
+    pub(crate) removed: Vec<T>,
+    pub(crate) modified: Vec<Modified<T>>,
+}
+
+impl<T> Default for Diff<T> {
+    fn default() -> Self {
+        Self {
+            added: Vec::new(),
+            removed: Vec::new(),
+            modified: Vec::new(),
+        }
+    }
+}
+
+impl<T: Diffable> Diff<T> {
+    /// Compare two lists of items. Items are matched by their [`key`](Diffable::key), so an item
+    /// whose key changes is reported as removed and added. Where several items share a key,
+    /// unchanged items are matched first, then the rest are matched in order.
+    pub fn between(old: &[T], new: &[T]) -> Self {
+        let mut items: BTreeMap<String, (Vec<&T>, Vec<&T>)> = BTreeMap::new();
+        for item in old {
+            items.entry(item.key()).or_default().0.push(item);
+        }
+        for item in new {
+            items.entry(item.key()).or_default().1.push(item);
+        }
+
+        let mut diff = Self::default();
+        for (old_items, mut new_items) in items.into_values() {
+            let mut unmatched = Vec::new();
+            for old_item in old_items {
+                match new_items
+                    .iter()
+                    .position(|new_item| old_item.changes(new_item).is_empty())
+                {
+                    Some(i) => {
+                        new_items.remove(i);
+                    }
+                    None => unmatched.push(old_item),
+                }
+            }
+
+            let mut new_items = new_items.into_iter();
+            for old_item in unmatched {
+                match new_items.next() {
+                    Some(new_item) => diff.modified.push(Modified {
+                        old: old_item.clone(),
+                        new: new_item.clone(),
+                        changes: old_item.changes(new_item),
+                    }),
+                    None => diff.removed.push(old_item.clone()),
+                }
+            }
+            diff.added.extend(new_items.cloned());
+        }
+        diff
+    }
+}
+
+impl<T> Diff<T> {
+    /// Items only in the new data.
+    pub fn added(&self) -> &Vec<T> {
+        &self.added
+    }
+
+    /// Items only in the old data.
+    pub fn removed(&self) -> &Vec<T> {
+        &self.removed
+    }
+
+    /// Items in both, that have changed.
+    pub fn modified(&self) -> &Vec<Modified<T>> {
+        &self.modified
+    }
+
+    /// Are the old and new data the same?
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+impl<T: Diffable> Display for Diff<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for item in &self.added {
+            writeln!(f, "+ {} {}", T::NAME, item.key())?;
+        }
+        for item in &self.removed {
+            writeln!(f, "- {} {}", T::NAME, item.key())?;
+        }
+        for modified in &self.modified {
+            writeln!(f, "~ {} {}", T::NAME, modified.old.key())?;
+            for change in &modified.changes {
+                writeln!(f, "    {}: {} -> {}", change.field, change.old, change.new)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An item that can be compared with [`Diff`].
+pub trait Diffable: Clone {
+    /// What kind of item this is, for reports.
+    const NAME: &'static str;
+
+    /// The key that identifies this item between two lists.
+    fn key(&self) -> String;
+
+    /// The fields that have changed between this item and `new`.
+    fn changes(&self, new: &Self) -> Vec<FieldChange>;
+}
+
+/// Format a position for reports.
+fn position(latitude: f64, longitude: f64) -> String {
+    format!("{:.6}, {:.6}", latitude, longitude)
+}
+
+/// Have two positions changed by more than rounding error?
+fn moved(old: (f64, f64), new: (f64, f64)) -> bool {
+    (old.0 - new.0).abs() > 1e-9 || (old.1 - new.1).abs() > 1e-9
+}
+
+impl Diffable for NavAid {
+    const NAME: &'static str = "navaid";
+
+    /// Navaids are identified by their identifier and name, as identifiers are not unique.
+    fn key(&self) -> String {
+        format!("{} {}", self.id, self.name)
+    }
+
+    fn changes(&self, new: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        if self.kind != new.kind {
+            changes.push(FieldChange::new(
+                "kind",
+                format!("{:?}", self.kind),
+                format!("{:?}", new.kind),
+            ));
+        }
+        if self.frequency_khz != new.frequency_khz {
+            changes.push(FieldChange::new(
+                "frequency_khz",
+                self.frequency_khz,
+                new.frequency_khz,
+            ));
+        }
+        if moved(
+            (self.latitude, self.longitude),
+            (new.latitude, new.longitude),
+        ) {
+            changes.push(FieldChange::new(
+                "position",
+                position(self.latitude, self.longitude),
+                position(new.latitude, new.longitude),
+            ));
+        }
+        if self.elevation != new.elevation {
+            changes.push(FieldChange::new("elevation", self.elevation, new.elevation));
+        }
+        changes
+    }
+}
+
+impl Diffable for Intersection {
+    const NAME: &'static str = "intersection";
+
+    fn key(&self) -> String {
+        self.designator.clone()
+    }
+
+    fn changes(&self, new: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        if moved(
+            (self.latitude, self.longitude),
+            (new.latitude, new.longitude),
+        ) {
+            changes.push(FieldChange::new(
+                "position",
+                position(self.latitude, self.longitude),
+                position(new.latitude, new.longitude),
+            ));
+        }
+        changes
+    }
+}
+
+impl Diffable for Airway {
+    const NAME: &'static str = "airway";
+
+    fn key(&self) -> String {
+        self.designator.clone()
+    }
+
+    fn changes(&self, new: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        let sequence = |airway: &Airway| {
+            airway
+                .waypoints
+                .iter()
+                .map(|wp| wp.designator.clone())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let (old_sequence, new_sequence) = (sequence(self), sequence(new));
+        if old_sequence != new_sequence {
+            changes.push(FieldChange::new("waypoints", old_sequence, new_sequence));
+        }
+
+        for old_wp in &self.waypoints {
+            if let Some(new_wp) = new
+                .waypoints
+                .iter()
+                .find(|wp| wp.designator == old_wp.designator)
+            {
+                if old_wp.lower_limit != new_wp.lower_limit {
+                    changes.push(FieldChange::new(
+                        format!("{} lower_limit", old_wp.designator),
+                        &old_wp.lower_limit,
+                        &new_wp.lower_limit,
+                    ));
+                }
+                if old_wp.upper_limit != new_wp.upper_limit {
+                    changes.push(FieldChange::new(
+                        format!("{} upper_limit", old_wp.designator),
+                        &old_wp.upper_limit,
+                        &new_wp.upper_limit,
+                    ));
+                }
+            }
+        }
+        changes
+    }
+}
+
+impl Diffable for Airport {
+    const NAME: &'static str = "airport";
+
+    fn key(&self) -> String {
+        self.icao.clone()
+    }
+
+    fn changes(&self, new: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        if self.name != new.name {
+            changes.push(FieldChange::new("name", &self.name, &new.name));
+        }
+        if moved(
+            (self.latitude, self.longitude),
+            (new.latitude, new.longitude),
+        ) {
+            changes.push(FieldChange::new(
+                "position",
+                position(self.latitude, self.longitude),
+                position(new.latitude, new.longitude),
+            ));
+        }
+        if self.elevation != new.elevation {
+            changes.push(FieldChange::new("elevation", self.elevation, new.elevation));
+        }
+
+        let titles = |charts: &Vec<Chart>| {
+            charts
+                .iter()
+                .map(|c| c.title.clone())
+                .collect::<Vec<_>>()
+                .join("; ")
+        };
+        let runways = |runways: &Vec<Runway>| {
+            runways
+                .iter()
+                .map(|r| format!("{} {}x{}", r.designator, r.length, r.width))
+                .collect::<Vec<_>>()
+                .join("; ")
+        };
+        let (old_runways, new_runways) = (runways(&self.runways), runways(&new.runways));
+        if old_runways != new_runways {
+            changes.push(FieldChange::new("runways", old_runways, new_runways));
+        }
+        if self.declared_distances != new.declared_distances {
+            let distances = |distances: &Vec<DeclaredDistances>| {
+                distances
+                    .iter()
+                    .map(|d| {
+                        let metres = |m: Option<Metres>| {
+                            m.map_or_else(|| "NU".to_string(), |m| m.0.to_string())
+                        };
+                        let runway = match &d.intersection {
+                            Some(intersection) => format!("{} ({})", d.runway, intersection),
+                            None => d.runway.clone(),
+                        };
+                        format!(
+                            "{} {}/{}/{}/{}",
+                            runway,
+                            metres(d.tora),
+                            metres(d.toda),
+                            metres(d.asda),
+                            metres(d.lda)
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            };
+            changes.push(FieldChange::new(
+                "declared_distances",
+                distances(&self.declared_distances),
+                distances(&new.declared_distances),
+            ));
+        }
+
+        let communications = |communications: &Vec<CommunicationService>| {
+            communications
+                .iter()
+                .map(|c| {
+                    let frequencies = c
+                        .frequencies_khz
+                        .iter()
+                        .map(|f| format!("{}.{:03}", f / 1000, f % 1000))
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    format!("{} {} {}", c.service, c.call_sign, frequencies)
+                })
+                .collect::<Vec<_>>()
+                .join("; ")
+        };
+        let (old_communications, new_communications) = (
+            communications(&self.communications),
+            communications(&new.communications),
+        );
+        if old_communications != new_communications {
+            changes.push(FieldChange::new(
+                "communications",
+                old_communications,
+                new_communications,
+            ));
+        }
+
+        let navaids = |navaids: &Vec<NavAid>| {
+            navaids
+                .iter()
+                .map(|n| {
+                    format!(
+                        "{:?} {} {} {} kHz ({})",
+                        n.kind,
+                        n.id,
+                        n.runway.as_deref().unwrap_or("-"),
+                        n.frequency_khz,
+                        position(n.latitude, n.longitude)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("; ")
+        };
+        let (old_navaids, new_navaids) = (navaids(&self.navaids), navaids(&new.navaids));
+        if old_navaids != new_navaids {
+            changes.push(FieldChange::new("navaids", old_navaids, new_navaids));
+        }
+
+        let (old_hours, new_hours) = (&self.operational_hours, &new.operational_hours);
+        if old_hours.operator != new_hours.operator {
+            changes.push(FieldChange::new(
+                "operator_hours",
+                &old_hours.operator,
+                &new_hours.operator,
+            ));
+        }
+        if old_hours.ats != new_hours.ats {
+            changes.push(FieldChange::new(
+                "ats_hours",
+                &old_hours.ats,
+                &new_hours.ats,
+            ));
+        }
+        let (old_handling, new_handling) = (&self.handling_services, &new.handling_services);
+        if old_handling.fuel_types != new_handling.fuel_types {
+            changes.push(FieldChange::new(
+                "fuel_types",
+                old_handling.fuel_types.join(", "),
+                new_handling.fuel_types.join(", "),
+            ));
+        }
+        if old_handling.has_de_icing != new_handling.has_de_icing {
+            changes.push(FieldChange::new(
+                "de_icing",
+                old_handling.has_de_icing,
+                new_handling.has_de_icing,
+            ));
+        }
+        let category = |rff: &RescueFirefighting| {
+            rff.category_number
+                .map_or_else(|| "-".to_string(), |c| c.to_string())
+        };
+        let (old_category, new_category) = (
+            category(&self.rescue_firefighting),
+            category(&new.rescue_firefighting),
+        );
+        if old_category != new_category {
+            changes.push(FieldChange::new("rff_category", old_category, new_category));
+        }
+
+        let (old_charts, new_charts) = (titles(&self.charts), titles(&new.charts));
+        if old_charts != new_charts {
+            changes.push(FieldChange::new("charts", old_charts, new_charts));
+        }
+        changes
+    }
+}
+
+/// The navigation data of an eAIP at one point in time.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot {
+    pub(crate) navaids: Navaids,
+    pub(crate) intersections: Intersections,
+    pub(crate) airways: Airways,
+    pub(crate) airports: Airports,
+}
+
+#[async_trait]
+impl FromEAIP for Snapshot {
+    type Output = Self;
+
+    /// Fetch the navaids, intersections, airways and airports. Each airport listed in the table
+    /// of contents is fetched from its AD 2 page. If that page is missing or can't be parsed,
+    /// only the ICAO code and name of the airport are kept, as with [`Airports`].
+    async fn from_eaip(eaip: &EAIP, airac: AIRAC) -> Result<Self::Output> {
+        let mut airports = Airports::new();
+        for listed in Airports::from_eaip(eaip, airac.clone()).await? {
+            match Airport::from_eaip(eaip, airac.clone(), listed.icao.clone()).await {
+                Ok(airport) => airports.push(airport),
+                Err(
+                    e @ (Error::EAIPMissingPage(..)
+                    | Error::CannotScrapeData(_)
+                    | Error::ParseError(..)),
+                ) => {
+                    log::warn!("Cannot fetch airport {}: {}", listed.icao, e);
+                    airports.push(listed);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(Self {
+            navaids: Navaids::from_eaip(eaip, airac.clone()).await?,
+            intersections: Intersections::from_eaip(eaip, airac.clone()).await?,
+            airways: Airways::from_eaip(eaip, airac).await?,
+            airports,
+        })
+    }
+}
+
+impl Snapshot {
+    /// Create a snapshot from data that has already been fetched.
+    pub fn new(
+        navaids: Navaids,
+        intersections: Intersections,
+        airways: Airways,
+        airports: Airports,
+    ) -> Self {
+        Self {
+            navaids,
+            intersections,
+            airways,
+            airports,
+        }
+    }
+
+    /// Compare this snapshot with a newer one.
+    pub fn diff(&self, new: &Snapshot) -> SnapshotDiff {
+        SnapshotDiff {
+            navaids: Diff::between(&self.navaids, &new.navaids),
+            intersections: Diff::between(&self.intersections, &new.intersections),
+            airways: Diff::between(&self.airways, &new.airways),
+            airports: Diff::between(&self.airports, &new.airports),
+        }
+    }
+
+    /// The navaids in this snapshot.
+    pub fn navaids(&self) -> &Navaids {
+        &self.navaids
+    }
+
+    /// The intersections in this snapshot.
+    pub fn intersections(&self) -> &Intersections {
+        &self.intersections
+    }
+
+    /// The airways in this snapshot.
+    pub fn airways(&self) -> &Airways {
+        &self.airways
+    }
+
+    /// The airports in this snapshot.
+    pub fn airports(&self) -> &Airports {
+        &self.airports
+    }
+}
+
+/// The differences between two [`Snapshot`]s. This can be displayed as a human-readable report.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapshotDiff {
+    pub(crate) navaids: Diff<NavAid>,
+    pub(crate) intersections: Diff<Intersection>,
+    pub(crate) airways: Diff<Airway>,
+    pub(crate) airports: Diff<Airport>,
+}
+
+impl SnapshotDiff {
+    /// Changes to navaids.
+    pub fn navaids(&self) -> &Diff<NavAid> {
+        &self.navaids
+    }
+
+    /// Changes to intersections.
+    pub fn intersections(&self) -> &Diff<Intersection> {
+        &self.intersections
+    }
+
+    /// Changes to airways.
+    pub fn airways(&self) -> &Diff<Airway> {
+        &self.airways
+    }
+
+    /// Changes to airports.
+    pub fn airports(&self) -> &Diff<Airport> {
+        &self.airports
+    }
+
+    /// Are the two snapshots the same?
+    pub fn is_empty(&self) -> bool {
+        self.navaids.is_empty()
+            && self.intersections.is_empty()
+            && self.airways.is_empty()
+            && self.airports.is_empty()
+    }
+}
+
+impl Display for SnapshotDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes.");
+        }
+        write!(f, "{}", self.navaids)?;
+        write!(f, "{}", self.intersections)?;
+        write!(f, "{}", self.airways)?;
+        write!(f, "{}", self.airports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn intersection(designator: &str, latitude: f64, longitude: f64) -> Intersection {
+        Intersection {
+            designator: designator.to_string(),
+            latitude,
+            longitude,
+        }
+    }
+
+    fn airway(designator: &str, waypoints: &[(&str, &str)]) -> Airway {
+        Airway {
+            designator: designator.to_string(),
+            waypoints: waypoints
+                .iter()
+                .map(|(designator, upper)| AirwayWaypoint {
+                    designator: designator.to_string(),
+                    upper_limit: upper.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_diff_intersections() {
+        let old = vec![
+            intersection("ABBEW", 50.1, -1.0),
+            intersection("BEDEK", 51.0, 0.5),
+        ];
+        let new = vec![
+            intersection("BEDEK", 51.0, 0.6),
+            intersection("CAWDL", 52.0, 0.0),
+        ];
+        let diff = Diff::between(&old, &new);
+        assert_eq!("CAWDL", diff.added()[0].designator());
+        assert_eq!("ABBEW", diff.removed()[0].designator());
+        assert_eq!(
+            vec![FieldChange::new(
+                "position",
+                "51.000000, 0.500000",
+                "51.000000, 0.600000"
+            )],
+            diff.modified()[0].changes
+        );
+        assert_eq!(
+            "+ intersection CAWDL\n- intersection ABBEW\n~ intersection BEDEK\n    position: 51.000000, 0.500000 -> 51.000000, 0.600000\n",
+            diff.to_string()
+        );
+    }
+
+    #[test]
+    fn test_diff_airways() {
+        let old = vec![airway("L9", &[("ABC", "FL195"), ("DEFGH", "FL195")])];
+        let new = vec![airway(
+            "L9",
+            &[("ABC", "FL245"), ("DEFGH", "FL195"), ("IJK", "FL195")],
+        )];
+        let diff = Diff::between(&old, &new);
+        let changes = diff.modified()[0].changes();
+        assert_eq!(2, changes.len());
+        assert_eq!("waypoints", changes[0].field());
+        assert_eq!("ABC DEFGH IJK", changes[0].new_value());
+        assert_eq!("ABC upper_limit", changes[1].field());
+
+        assert!(Diff::between(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_diff_duplicate_keys() {
+        let old = vec![
+            intersection("ABBEW", 50.0, -1.0),
+            intersection("ABBEW", 55.0, -3.0),
+        ];
+        let new = vec![
+            intersection("ABBEW", 55.0, -3.0),
+            intersection("ABBEW", 50.0, -1.1),
+            intersection("ABBEW", 58.0, -4.0),
+        ];
+        let diff = Diff::between(&old, &new);
+        assert!(diff.removed().is_empty());
+        assert_eq!(1, diff.added().len());
+        assert_eq!(58.0, diff.added()[0].latitude());
+        assert_eq!(1, diff.modified().len());
+        assert_eq!(50.0, diff.modified()[0].old().latitude());
+        assert_eq!(-1.1, diff.modified()[0].new_value().longitude());
+
+        let diff = Diff::between(&new, &old);
+        assert_eq!(58.0, diff.removed()[0].latitude());
+        assert!(diff.added().is_empty());
+        assert!(Diff::between(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_diff_airport_communications() {
+        let airport = |frequencies_khz: Vec<usize>| Airport {
+            icao: "EGPD".to_string(),
+            communications: vec![CommunicationService {
+                service: "TWR".to_string(),
+                kind: CommunicationServiceKind::Tower,
+                call_sign: "ABERDEEN TOWER".to_string(),
+                frequencies_khz,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let old = vec![airport(vec![118105])];
+        let new = vec![airport(vec![118105, 123300])];
+        let diff = Diff::between(&old, &new);
+        assert_eq!(
+            vec![FieldChange::new(
+                "communications",
+                "TWR ABERDEEN TOWER 118.105",
+                "TWR ABERDEEN TOWER 118.105 123.300"
+            )],
+            diff.modified()[0].changes
+        );
+        assert!(Diff::between(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_diff_airport_navaids() {
+        let airport = |runway: &str| Airport {
+            icao: "EGPD".to_string(),
+            navaids: vec![NavAid {
+                id: "I-ABD".to_string(),
+                kind: NavAidKind::LOC,
+                frequency_khz: 109900,
+                runway: Some(runway.to_string()),
+                latitude: 57.19,
+                longitude: -2.18,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let old = vec![airport("16")];
+        let new = vec![airport("34")];
+        let diff = Diff::between(&old, &new);
+        assert_eq!(
+            vec![FieldChange::new(
+                "navaids",
+                "LOC I-ABD 16 109900 kHz (57.190000, -2.180000)",
+                "LOC I-ABD 34 109900 kHz (57.190000, -2.180000)"
+            )],
+            diff.modified()[0].changes
+        );
+        assert!(Diff::between(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_diff_airport_services() {
+        let old = vec![Airport {
+            icao: "EGPD".to_string(),
+            operational_hours: OperationalHours {
+                operator: "H24".to_string(),
+                ..Default::default()
+            },
+            handling_services: HandlingServices {
+                fuel_types: vec!["AVGAS 100LL".to_string(), "JET A-1".to_string()],
+                ..Default::default()
+            },
+            rescue_firefighting: RescueFirefighting {
+                category_number: Some(9),
+                ..Default::default()
+            },
+            ..Default::default()
+        }];
+        let mut new = old.clone();
+        new[0].operational_hours.operator = "0530-2200".to_string();
+        new[0].handling_services.fuel_types = vec!["JET A-1".to_string()];
+        new[0].rescue_firefighting.category_number = Some(7);
+        let diff = Diff::between(&old, &new);
+        assert_eq!(
+            vec![
+                FieldChange::new("operator_hours", "H24", "0530-2200"),
+                FieldChange::new("fuel_types", "AVGAS 100LL, JET A-1", "JET A-1"),
+                FieldChange::new("rff_category", "9", "7"),
+            ],
+            diff.modified()[0].changes
+        );
+        assert!(Diff::between(&old, &old).is_empty());
+    }
+}
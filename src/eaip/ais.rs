@@ -60,5 +60,6 @@ lazy_static! {
         icao_prefix: "EH",
         url: "https://lvnl.nl",
         eaip: EAIP::new_with_offset("https://eaip.lvnl.nl", "EH", "en-GB", Duration::weeks(2))
+            .with_history_location("/index.html")
     };
 }
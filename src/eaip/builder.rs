@@ -0,0 +1,175 @@
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::Duration;
+use reqwest::header::HeaderMap;
+use reqwest::{Certificate, Proxy};
+
+use super::http::{ReqwestSource, RetryPolicy, USER_AGENT};
+use super::source::PageSource;
+use super::EAIP;
+use crate::error::Result;
+
+/// A builder for an [`EAIP`], allowing the HTTP client used to fetch pages to be configured.
+///
+/// ```no_run
+/// # fn main() -> eaip::error::Result<()> {
+/// use eaip::prelude::*;
+///
+/// let eaip = EAIP::builder("https://www.aurora.nats.co.uk/htmlAIP/Publications", "EG", "en-GB")
+///     .user_agent("my-tool/1.0 (ops@example.com)")
+///     .timeout(std::time::Duration::from_secs(10))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EAIPBuilder {
+    base_uri: String,
+    country_code: String,
+    locale: String,
+    publish_offset: Duration,
+    history_location: Option<String>,
+    client: Option<reqwest::Client>,
+    user_agent: String,
+    proxies: Vec<Proxy>,
+    root_certificates: Vec<Certificate>,
+    headers: HeaderMap,
+    timeout: StdDuration,
+    retry_policy: RetryPolicy,
+    rate_limit: Option<StdDuration>,
+    source: Option<Arc<dyn PageSource>>,
+}
+
+impl EAIPBuilder {
+    pub(crate) fn new(base_uri: String, country_code: String, locale: String) -> Self {
+        Self {
+            base_uri,
+            country_code,
+            locale,
+            publish_offset: Duration::zero(),
+            history_location: None,
+            client: None,
+            user_agent: USER_AGENT.to_string(),
+            proxies: Vec::new(),
+            root_certificates: Vec::new(),
+            headers: HeaderMap::new(),
+            timeout: StdDuration::from_secs(30),
+            retry_policy: RetryPolicy::default(),
+            rate_limit: None,
+            source: None,
+        }
+    }
+
+    /// Set the publishing offset. If the eAIP is published two weeks in advance, the offset
+    /// would be `Duration::weeks(2)`.
+    pub fn publish_offset(mut self, publish_offset: Duration) -> Self {
+        self.publish_offset = publish_offset;
+        self
+    }
+
+    /// Set the location of the page listing the cycles published by the eAIP, relative to the
+    /// base URI. See [`EAIP::with_history_location`].
+    pub fn history_location<S: Into<String>>(mut self, location: S) -> Self {
+        self.history_location = Some(location.into());
+        self
+    }
+
+    /// Use an already configured HTTP client. The user agent, proxy, root certificate, header
+    /// and timeout options of this builder are ignored.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Set the user agent sent with each request.
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Send requests through a proxy. This can be called more than once to add several proxies.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Trust an additional root certificate, for example for a corporate TLS proxy.
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Set headers sent with each request.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Set the timeout for each request. Defaults to 30 seconds.
+    pub fn timeout(mut self, timeout: StdDuration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set how failed requests are retried.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Limit requests to each host to one per `min_interval`.
+    pub fn rate_limit(mut self, min_interval: StdDuration) -> Self {
+        self.rate_limit = Some(min_interval);
+        self
+    }
+
+    /// Fetch pages from the given [`PageSource`] rather than over HTTP. All HTTP options of this
+    /// builder are ignored.
+    pub fn source<P: PageSource + 'static>(mut self, source: P) -> Self {
+        self.source = Some(Arc::new(source));
+        self
+    }
+
+    /// Build the [`EAIP`]. This fails if the HTTP client cannot be configured, for example if
+    /// the TLS backend cannot be initialised.
+    pub fn build(self) -> Result<EAIP> {
+        let source = match self.source {
+            Some(source) => source,
+            None => {
+                let client = match self.client {
+                    Some(client) => client,
+                    None => {
+                        let mut builder = reqwest::Client::builder()
+                            .user_agent(self.user_agent)
+                            .default_headers(self.headers)
+                            .timeout(self.timeout);
+                        for proxy in self.proxies {
+                            builder = builder.proxy(proxy);
+                        }
+                        for certificate in self.root_certificates {
+                            builder = builder.add_root_certificate(certificate);
+                        }
+                        builder.build()?
+                    }
+                };
+                let mut source = ReqwestSource::new()
+                    .with_client(client)
+                    .with_retry_policy(self.retry_policy);
+                if let Some(min_interval) = self.rate_limit {
+                    source = source.with_rate_limit(min_interval);
+                }
+                Arc::new(source)
+            }
+        };
+
+        Ok(EAIP {
+            base_uri: self.base_uri,
+            country_code: self.country_code,
+            locale: self.locale,
+            publish_offset: self.publish_offset,
+            history_location: self.history_location,
+            source,
+        })
+    }
+}
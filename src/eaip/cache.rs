@@ -0,0 +1,245 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use airac::AIRAC;
+use async_trait::async_trait;
+
+use super::source::PageSource;
+use super::EAIP;
+use crate::error::Result;
+use crate::parts::*;
+
+/// A [`PageSource`] that stores pages fetched by another source on disk.
+///
+/// Pages are keyed by the eAIP base URI, country code, locale and publishing offset, the AIRAC,
+/// the [`Part`] and the [`EAIPType`]. Each page is written to a `.part` file first and renamed
+/// once complete, so an interrupted write is never read back as a cached page. A page for an AIRAC
+/// that has already ended can no longer change, so it is kept forever. Pages for the current and
+/// future AIRACs are revalidated against the inner source once they are older than the
+/// time-to-live. If revalidation fails for any reason, the stale page is used and kept.
+#[derive(Debug)]
+pub struct CachedSource<P: PageSource> {
+    inner: P,
+    dir: PathBuf,
+    ttl: Duration,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+    revalidations: AtomicUsize,
+}
+
+/// Statistics about a [`CachedSource`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub(crate) hits: usize,
+    pub(crate) misses: usize,
+    pub(crate) revalidations: usize,
+    pub(crate) entries: usize,
+    pub(crate) bytes: u64,
+}
+
+impl CacheStats {
+    /// The number of pages served from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// The number of pages that weren't in the cache and were fetched from the inner source.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// The number of cached pages that were older than the time-to-live and were fetched again.
+    pub fn revalidations(&self) -> usize {
+        self.revalidations
+    }
+
+    /// The number of pages currently stored on disk.
+    pub fn entries(&self) -> usize {
+        self.entries
+    }
+
+    /// The total size of the pages currently stored on disk in bytes.
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+}
+
+impl<P: PageSource> CachedSource<P> {
+    /// Create a new cache in the directory `dir`, fetching missing pages from `inner`.
+    /// Pages for the current and future AIRACs are revalidated after a day.
+    pub fn new<D: Into<PathBuf>>(inner: P, dir: D) -> Self {
+        Self {
+            inner,
+            dir: dir.into(),
+            ttl: Duration::from_secs(60 * 60 * 24),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+            revalidations: AtomicUsize::new(0),
+        }
+    }
+
+    /// Set the time-to-live for pages of the current and future AIRACs.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// The source that pages missing from the cache are fetched from.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// The directory the cache is stored in.
+    pub fn dir(&self) -> &PathBuf {
+        &self.dir
+    }
+
+    /// Get statistics about this cache.
+    pub fn stats(&self) -> Result<CacheStats> {
+        let mut stats = CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            revalidations: self.revalidations.load(Ordering::Relaxed),
+            ..Default::default()
+        };
+        if self.dir.exists() {
+            count_entries(&self.dir, &mut stats)?;
+        }
+        Ok(stats)
+    }
+
+    /// Remove all cached pages for the given AIRAC, from every eAIP. Returns the number of
+    /// pages removed.
+    pub fn purge_airac(&self, airac: &AIRAC) -> Result<usize> {
+        let mut removed = 0;
+        if !self.dir.exists() {
+            return Ok(removed);
+        }
+        for eaip_dir in std::fs::read_dir(&self.dir)? {
+            let airac_dir = eaip_dir?.path().join(airac.to_string());
+            if airac_dir.is_dir() {
+                let mut stats = CacheStats::default();
+                count_entries(&airac_dir, &mut stats)?;
+                std::fs::remove_dir_all(&airac_dir)?;
+                removed += stats.entries;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Remove every cached page.
+    pub fn purge_all(&self) -> Result<()> {
+        if self.dir.exists() {
+            std::fs::remove_dir_all(&self.dir)?;
+        }
+        Ok(())
+    }
+
+    /// The path a page is cached at.
+    fn path(&self, eaip: &EAIP, airac: &AIRAC, part: &Part, typ: EAIPType) -> PathBuf {
+        self.dir
+            .join(sanitise(&format!(
+                "{} {} {} {}",
+                eaip.base_uri(),
+                eaip.country_code(),
+                eaip.locale(),
+                eaip.publish_offset().num_seconds()
+            )))
+            .join(airac.to_string())
+            .join(typ.to_string())
+            .join(format!("{}.{}", sanitise(&part.to_string()), typ))
+    }
+
+    /// Is a cached page at `path` still fresh?
+    fn is_fresh(&self, path: &Path, airac: &AIRAC) -> bool {
+        if *airac < AIRAC::current() {
+            return true;
+        }
+        std::fs::metadata(path)
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .map(|age| age < self.ttl)
+            .unwrap_or(false)
+    }
+}
+
+#[async_trait]
+impl<P: PageSource> PageSource for CachedSource<P> {
+    async fn get_page(
+        &self,
+        eaip: &EAIP,
+        airac: AIRAC,
+        part: Part,
+        typ: EAIPType,
+    ) -> Result<String> {
+        let path = self.path(eaip, &airac, &part, typ);
+        let cached = std::fs::read_to_string(&path).ok();
+
+        if let Some(data) = &cached {
+            if self.is_fresh(&path, &airac) {
+                log::debug!("Cache hit: {}", path.display());
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                return Ok(data.clone());
+            }
+            self.revalidations.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+
+        match self.inner.get_page(eaip, airac, part, typ).await {
+            Ok(data) => {
+                log::debug!("Caching page: {}", path.display());
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                let mut part = path.clone().into_os_string();
+                part.push(".part");
+                std::fs::write(&part, &data)?;
+                std::fs::rename(&part, &path)?;
+                Ok(data)
+            }
+            Err(e) => {
+                if let Some(data) = cached {
+                    log::warn!("Revalidation failed, using stale page: {}", e);
+                    return Ok(data);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_file(&self, eaip: &EAIP, location: &str) -> Result<Vec<u8>> {
+        self.inner.get_file(eaip, location).await
+    }
+}
+
+/// Make a string safe to use as a single path component.
+fn sanitise(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Count the files and their total size within a directory.
+fn count_entries(dir: &Path, stats: &mut CacheStats) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let meta = entry.metadata()?;
+        if meta.is_dir() {
+            count_entries(&entry.path(), stats)?;
+        } else if !matches!(entry.path().extension(), Some(ext) if ext == "part") {
+            // Partly written pages left by an interrupted write aren't entries.
+            stats.entries += 1;
+            stats.bytes += meta.len();
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use airac::AIRAC;
+use async_trait::async_trait;
+use rand::Rng;
+use reqwest::{header::RETRY_AFTER, StatusCode};
+
+use super::source::PageSource;
+use super::EAIP;
+use crate::error::{Error, Result};
+use crate::parts::*;
+
+/// The user agent sent with requests, unless another is configured.
+pub const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// How failed requests to an eAIP are retried.
+///
+/// Requests are retried if the server reports a temporary failure (a 5xx status, `408 Request
+/// Timeout` or `429 Too Many Requests`), or if the connection fails or times out, including while
+/// reading the response body. Between attempts, the delay doubles from the initial backoff up to
+/// the maximum backoff, or waits as long as the server asks with `Retry-After` if that is longer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub(crate) max_retries: u32,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a new retry policy, retrying up to `max_retries` times.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Default::default()
+        }
+    }
+
+    /// A retry policy that never retries.
+    pub fn none() -> Self {
+        Self::new(0)
+    }
+
+    /// Set the delay before the first retry, and the maximum delay between retries.
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Set whether the delay between retries should be randomised, which prevents many clients
+    /// from retrying at the same time.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// The maximum number of times a request is retried.
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// The delay before retrying after the given (zero-indexed) attempt failed.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .checked_mul(2u32.saturating_pow(attempt))
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+        if self.jitter {
+            // Equal jitter: keep half of the delay, and randomise the other half.
+            let half = backoff / 2;
+            half + half.mul_f64(rand::thread_rng().gen::<f64>())
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Is a request that failed with this status worth retrying?
+fn is_transient(status: StatusCode) -> bool {
+    status.is_server_error()
+        || status == StatusCode::REQUEST_TIMEOUT
+        || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+/// Parse a `Retry-After` header, given either as a number of seconds or as an HTTP date.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    (at.with_timezone(&chrono::Utc) - chrono::Utc::now())
+        .to_std()
+        .ok()
+}
+
+/// A [`PageSource`] that fetches pages from the live eAIP over HTTP.
+#[derive(Debug, Clone)]
+pub struct ReqwestSource {
+    client: reqwest::Client,
+    retry_policy: RetryPolicy,
+    rate_limit: Option<Duration>,
+    next_request: Arc<Mutex<HashMap<String, Instant>>>,
+}
+
+impl Default for ReqwestSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReqwestSource {
+    /// Create a new HTTP page source, using the default [`RetryPolicy`], a 30 second timeout
+    /// and no rate limit.
+    pub fn new() -> Self {
+        Self {
+            client: Self::client_with_timeout(Duration::from_secs(30)),
+            retry_policy: RetryPolicy::default(),
+            rate_limit: None,
+            next_request: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Use the given HTTP client for all requests. This allows the user agent, proxies, root
+    /// certificates, timeouts and default headers to be configured.
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Set how failed requests are retried.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Limit requests to each host to one per `min_interval`.
+    pub fn with_rate_limit(mut self, min_interval: Duration) -> Self {
+        self.rate_limit = Some(min_interval);
+        self
+    }
+
+    /// Set the timeout for each request. This replaces any client set with
+    /// [`with_client`](Self::with_client).
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.client = Self::client_with_timeout(timeout);
+        self
+    }
+
+    /// The retry policy for this source.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// The HTTP client used for requests.
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    fn client_with_timeout(timeout: Duration) -> reqwest::Client {
+        reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .timeout(timeout)
+            .build()
+            .expect("default HTTP client configuration is valid")
+    }
+
+    /// Wait until the rate limit allows another request to the host of `url`.
+    async fn wait_for_rate_limit(&self, url: &str) {
+        let min_interval = match self.rate_limit {
+            Some(min_interval) => min_interval,
+            None => return,
+        };
+        let host = url::Url::parse(url)
+            .ok()
+            .and_then(|u| u.host_str().map(|h| h.to_string()))
+            .unwrap_or_default();
+
+        let at = {
+            let mut next_request = self.next_request.lock().unwrap();
+            let now = Instant::now();
+            let at = next_request.get(&host).copied().unwrap_or(now).max(now);
+            next_request.insert(host, at + min_interval);
+            at
+        };
+        tokio::time::sleep_until(at.into()).await;
+    }
+
+    /// Send a GET request and read the response body, retrying transient failures. Returns
+    /// `None` if the page doesn't exist.
+    async fn fetch(&self, url: &str) -> Result<Option<Vec<u8>>> {
+        let mut attempt = 0;
+        loop {
+            self.wait_for_rate_limit(url).await;
+            let mut retry_after = None;
+            let err = match self.client.get(url).send().await {
+                Ok(res) if res.status().is_success() => match res.bytes().await {
+                    Ok(body) => return Ok(Some(body.to_vec())),
+                    Err(e) => Error::EAIPFetchError(e),
+                },
+                Ok(res) if is_transient(res.status()) => {
+                    retry_after = res
+                        .headers()
+                        .get(RETRY_AFTER)
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(parse_retry_after);
+                    Error::EAIPUnavailable(res.status().as_u16())
+                }
+                Ok(res)
+                    if res.status() == StatusCode::NOT_FOUND
+                        || res.status() == StatusCode::GONE =>
+                {
+                    log::debug!("Page not found ({}): {}", res.status(), url);
+                    return Ok(None);
+                }
+                Ok(res) => return Err(Error::EAIPRequestFailed(res.status().as_u16())),
+                Err(e) if e.is_timeout() || e.is_connect() => Error::EAIPFetchError(e),
+                Err(e) => return Err(e.into()),
+            };
+
+            if attempt >= self.retry_policy.max_retries {
+                return Err(err);
+            }
+            let delay = self
+                .retry_policy
+                .backoff(attempt)
+                .max(retry_after.unwrap_or_default());
+            log::warn!("Retrying {} in {:?}: {}", url, delay, err);
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+#[async_trait]
+impl PageSource for ReqwestSource {
+    async fn get_page(
+        &self,
+        eaip: &EAIP,
+        airac: AIRAC,
+        part: Part,
+        typ: EAIPType,
+    ) -> Result<String> {
+        let url = eaip.generate_url(airac.clone(), part.clone(), typ);
+        log::debug!("Getting page: {}", url);
+        match self.fetch(&url).await? {
+            Some(body) => Ok(String::from_utf8_lossy(&body).to_string()),
+            None => Err(Error::EAIPMissingPage(airac, part, typ)),
+        }
+    }
+
+    async fn get_file(&self, eaip: &EAIP, location: &str) -> Result<Vec<u8>> {
+        let url = format!("{}{}", eaip.base_uri(), location);
+        log::debug!("Getting file: {}", url);
+        match self.fetch(&url).await? {
+            Some(body) => Ok(body),
+            None => Err(Error::EAIPMissingFile(location.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{is_transient, parse_retry_after, RetryPolicy};
+    use reqwest::StatusCode;
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::new(5)
+            .with_backoff(Duration::from_secs(1), Duration::from_secs(5))
+            .with_jitter(false);
+        assert_eq!(Duration::from_secs(1), policy.backoff(0));
+        assert_eq!(Duration::from_secs(2), policy.backoff(1));
+        assert_eq!(Duration::from_secs(4), policy.backoff(2));
+        assert_eq!(Duration::from_secs(5), policy.backoff(3));
+        assert_eq!(Duration::from_secs(5), policy.backoff(40));
+
+        let policy = policy.with_jitter(true);
+        for attempt in 0..5 {
+            let backoff = policy.backoff(attempt);
+            assert!(backoff >= Duration::from_secs(1) / 2);
+            assert!(backoff <= Duration::from_secs(5));
+        }
+    }
+
+    #[test]
+    fn test_is_transient() {
+        assert!(is_transient(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(is_transient(StatusCode::TOO_MANY_REQUESTS));
+        assert!(!is_transient(StatusCode::NOT_FOUND));
+        assert!(!is_transient(StatusCode::FORBIDDEN));
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(Some(Duration::from_secs(120)), parse_retry_after("120"));
+        let at = chrono::Utc::now() + chrono::Duration::seconds(90);
+        let delay = parse_retry_after(&at.format("%a, %d %b %Y %H:%M:%S GMT").to_string()).unwrap();
+        assert!(delay > Duration::from_secs(80) && delay <= Duration::from_secs(90));
+        assert_eq!(None, parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT"));
+        assert_eq!(None, parse_retry_after("soon"));
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use airac::AIRAC;
+use scraper::{Html, Selector};
+use sha2::{Digest, Sha256};
+
+use super::EAIP;
+use crate::error::{Error, Result};
+use crate::parts::*;
+
+/// The name of the manifest file written to the root of a mirrored package.
+pub const MANIFEST_NAME: &str = "manifest.sha256";
+
+/// Mirrors an entire eAIP package for one AIRAC to a local directory, reproducing the layout of
+/// the online eAIP so that it can be read back with a
+/// [`DirectorySource`](super::source::DirectorySource).
+///
+/// The mirror crawls from the table of contents pages, following every link that stays within
+/// the package, including charts. Each file is written to a `.part` file first and renamed once
+/// complete, so files that already exist in the directory are not downloaded again and an
+/// interrupted mirror can be resumed by running it again. Files that can't be fetched are
+/// recorded in the [`MirrorReport`] rather than stopping the mirror.
+#[derive(Debug, Clone)]
+pub struct Mirror<'a> {
+    eaip: &'a EAIP,
+    airac: AIRAC,
+    dir: PathBuf,
+    start_pages: Vec<Part>,
+}
+
+/// The result of running a [`Mirror`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MirrorReport {
+    pub(crate) downloaded: usize,
+    pub(crate) skipped: usize,
+    pub(crate) failed: Vec<String>,
+    pub(crate) manifest: PathBuf,
+}
+
+impl MirrorReport {
+    /// The number of files downloaded.
+    pub fn downloaded(&self) -> usize {
+        self.downloaded
+    }
+
+    /// The number of files that were already present from a previous mirror.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// The locations of files that were linked to but couldn't be fetched, because they are
+    /// missing or because of another error.
+    pub fn failed(&self) -> &Vec<String> {
+        &self.failed
+    }
+
+    /// The path of the manifest that was written.
+    pub fn manifest(&self) -> &PathBuf {
+        &self.manifest
+    }
+}
+
+impl<'a> Mirror<'a> {
+    /// Create a new mirror of the package for `airac` from `eaip` into the directory `dir`.
+    pub fn new<D: Into<PathBuf>>(eaip: &'a EAIP, airac: AIRAC, dir: D) -> Self {
+        Self {
+            eaip,
+            airac,
+            dir: dir.into(),
+            start_pages: vec![
+                Part::General(GEN::Overview(6)),
+                Part::EnRoute(ENR::TableOfContents),
+                Part::Aerodromes(AD::TableOfContents),
+            ],
+        }
+    }
+
+    /// Set the pages that crawling starts from. By default, these are the table of contents
+    /// pages for GEN, ENR and AD.
+    pub fn with_start_pages(mut self, start_pages: Vec<Part>) -> Self {
+        self.start_pages = start_pages;
+        self
+    }
+
+    /// Run the mirror, returning a report of what was done.
+    pub async fn run(&self) -> Result<MirrorReport> {
+        let package = self.eaip.generate_package_location(self.airac.clone());
+        let package_url = format!("{}{}/", self.eaip.base_uri(), package);
+        let selectors = [
+            "a[href]",
+            "link[href]",
+            "img[src]",
+            "frame[src]",
+            "iframe[src]",
+        ]
+        .iter()
+        .map(|s| Selector::parse(s).unwrap())
+        .collect::<Vec<_>>();
+
+        let mut report = MirrorReport::default();
+        let mut seen = BTreeSet::new();
+        let mut queue = VecDeque::new();
+        for part in &self.start_pages {
+            let location =
+                self.eaip
+                    .generate_location(self.airac.clone(), part.clone(), EAIPType::HTML);
+            if seen.insert(location.clone()) {
+                queue.push_back(location);
+            }
+        }
+
+        while let Some(location) = queue.pop_front() {
+            let path = self.dir.join(location.trim_start_matches('/'));
+            let data = if path.exists() {
+                report.skipped += 1;
+                std::fs::read(&path)?
+            } else {
+                match self.eaip.get_file(&location).await {
+                    Ok(data) => {
+                        if let Some(parent) = path.parent() {
+                            std::fs::create_dir_all(parent)?;
+                        }
+                        let mut part = path.clone().into_os_string();
+                        part.push(".part");
+                        std::fs::write(&part, &data)?;
+                        std::fs::rename(&part, &path)?;
+                        report.downloaded += 1;
+                        data
+                    }
+                    Err(e) => {
+                        log::warn!("Cannot mirror file {}: {}", location, e);
+                        report.failed.push(location);
+                        continue;
+                    }
+                }
+            };
+
+            if !(location.ends_with(".html") || location.ends_with(".htm")) {
+                continue;
+            }
+
+            let page_url = url::Url::parse(&format!("{}{}", self.eaip.base_uri(), location))
+                .map_err(Error::EAIPInvalidBaseURL)?;
+            let html = Html::parse_document(&String::from_utf8_lossy(&data));
+            for selector in &selectors {
+                for elem in html.select(selector) {
+                    let link = elem
+                        .value()
+                        .attr("href")
+                        .or_else(|| elem.value().attr("src"))
+                        .unwrap();
+                    let mut url = match page_url.join(link) {
+                        Ok(url) => url,
+                        Err(_) => continue,
+                    };
+                    url.set_fragment(None);
+                    url.set_query(None);
+                    if let Some(linked) = url.as_str().strip_prefix(&package_url) {
+                        let linked = format!("{}/{}", package, linked);
+                        if seen.insert(linked.clone()) {
+                            queue.push_back(linked);
+                        }
+                    }
+                }
+            }
+        }
+
+        report.manifest = self.write_manifest(&package, &seen)?;
+        Ok(report)
+    }
+
+    /// Write a manifest of SHA-256 hashes for every mirrored file in the package, in the format
+    /// used by `sha256sum`.
+    fn write_manifest(&self, package: &str, locations: &BTreeSet<String>) -> Result<PathBuf> {
+        let package_dir = self.dir.join(package.trim_start_matches('/'));
+        let mut manifest = String::new();
+        for location in locations {
+            let path = self.dir.join(location.trim_start_matches('/'));
+            if !path.exists() {
+                continue;
+            }
+            let hash = Sha256::digest(std::fs::read(&path)?);
+            for byte in hash {
+                write!(manifest, "{:02x}", byte).unwrap();
+            }
+            let relative = location
+                .strip_prefix(package)
+                .unwrap_or(location)
+                .trim_start_matches('/');
+            writeln!(manifest, "  {}", relative).unwrap();
+        }
+        std::fs::create_dir_all(&package_dir)?;
+        let manifest_path = package_dir.join(MANIFEST_NAME);
+        std::fs::write(&manifest_path, manifest)?;
+        Ok(manifest_path)
+    }
+}
@@ -1,20 +1,56 @@
+use std::sync::Arc;
+
 use airac::AIRAC;
 use chrono::Duration;
 
+use crate::parse::{cycles::Cycles, Parser};
 use crate::{error::Error, parts::*};
 
+use self::builder::EAIPBuilder;
+use self::http::ReqwestSource;
+use self::source::PageSource;
+
 /// Predefined [`EAIP`] objects for global aeronautical information services.
 pub mod ais;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Sources that pages of an eAIP can be fetched from.
+pub mod source;
+
+/// A builder for configuring how an eAIP is fetched.
+pub mod builder;
+
+/// Fetching of eAIP pages over HTTP.
+pub mod http;
+
+/// An on-disk cache of eAIP pages.
+pub mod cache;
+
+/// Mirroring of an entire eAIP package to a local directory.
+pub mod mirror;
+
+#[derive(Debug, Clone)]
 /// Details to an online aeronautical information publication
 pub struct EAIP {
     base_uri: String,
     country_code: String,
     locale: String,
     publish_offset: Duration,
+    history_location: Option<String>,
+    source: Arc<dyn PageSource>,
+}
+
+impl PartialEq for EAIP {
+    fn eq(&self, other: &Self) -> bool {
+        self.base_uri == other.base_uri
+            && self.country_code == other.country_code
+            && self.locale == other.locale
+            && self.publish_offset == other.publish_offset
+            && self.history_location == other.history_location
+    }
 }
 
+impl Eq for EAIP {}
+
 impl EAIP {
     /// Create a new aeronautical information publication.
     /// Note that some of the global AIP providers can be found in the `ais` module by enabling the `ais` feature.
@@ -36,9 +72,56 @@ impl EAIP {
             country_code: country_code.into(),
             locale: locale.into(),
             publish_offset,
+            history_location: None,
+            source: Arc::new(ReqwestSource::new()),
         }
     }
 
+    /// Create a builder for an aeronautical information publication, which allows the HTTP
+    /// client to be configured, for example with a user agent, proxy or root certificates.
+    pub fn builder<S: Into<String>>(base_uri: S, country_code: S, locale: S) -> EAIPBuilder {
+        EAIPBuilder::new(base_uri.into(), country_code.into(), locale.into())
+    }
+
+    /// Use the given [`PageSource`] to fetch pages from this eAIP, rather than fetching them from
+    /// the live eAIP over HTTP.
+    pub fn with_source<P: PageSource + 'static>(mut self, source: P) -> Self {
+        self.source = Arc::new(source);
+        self
+    }
+
+    /// Set the location of the page listing the cycles published by this eAIP, relative to the
+    /// base URI, if it isn't the standard history page.
+    pub fn with_history_location<S: Into<String>>(mut self, location: S) -> Self {
+        self.history_location = Some(location.into());
+        self
+    }
+
+    /// The base URI of this eAIP.
+    pub fn base_uri(&self) -> &String {
+        &self.base_uri
+    }
+
+    /// The ICAO country code used in page names of this eAIP.
+    pub fn country_code(&self) -> &String {
+        &self.country_code
+    }
+
+    /// The locale used in page names of this eAIP.
+    pub fn locale(&self) -> &String {
+        &self.locale
+    }
+
+    /// How far in advance of the AIRAC this eAIP is published.
+    pub fn publish_offset(&self) -> Duration {
+        self.publish_offset
+    }
+
+    /// The [`PageSource`] pages of this eAIP are fetched from.
+    pub fn source(&self) -> &dyn PageSource {
+        &*self.source
+    }
+
     /// Get a page from an eAIP for the current AIRAC.
     pub async fn get_current_page(&self, part: Part, typ: EAIPType) -> Result<String, Error> {
         let airac = AIRAC::current();
@@ -47,13 +130,56 @@ impl EAIP {
 
     /// Get a page from an eAIP for the specified AIRAC.
     pub async fn get_page(&self, airac: AIRAC, part: Part, typ: EAIPType) -> Result<String, Error> {
-        let url = self.generate_url(airac.clone(), part.clone(), typ);
-        log::debug!("Getting page: {}", url);
-        let res = reqwest::get(&url).await?;
-        if res.status().is_success() {
-            return Ok(res.text().await?);
+        self.source.get_page(self, airac, part, typ).await
+    }
+
+    /// Get any file from this eAIP by its location relative to the base URI.
+    pub async fn get_file(&self, location: &str) -> Result<Vec<u8>, Error> {
+        self.source.get_file(self, location).await
+    }
+
+    /// Has the package for the given AIRAC been published? This checks whether the package exists
+    /// in the eAIP, as packages are often published earlier than the publishing offset suggests.
+    pub async fn is_published(&self, airac: AIRAC) -> Result<bool, Error> {
+        match self
+            .get_page(airac, Part::EnRoute(ENR::TableOfContents), EAIPType::HTML)
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(Error::EAIPMissingPage(..)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The next AIRAC if its package has been published, otherwise the current AIRAC.
+    pub async fn latest_published_airac(&self) -> Result<AIRAC, Error> {
+        let next = AIRAC::current().next();
+        if self.is_published(next.clone()).await? {
+            Ok(next)
+        } else {
+            Ok(AIRAC::current())
         }
-        Err(Error::EAIPMissingPage(airac, part, typ))
+    }
+
+    /// The location of the page listing the cycles published by this eAIP, relative to the base
+    /// URI. Unless [set](Self::with_history_location), this is the standard history page,
+    /// `/history-<locale>.html`.
+    pub fn history_location(&self) -> String {
+        self.history_location
+            .clone()
+            .unwrap_or_else(|| format!("/history-{}.html", self.locale))
+    }
+
+    /// Get the AIRAC cycles this eAIP has published, from its [history page](Self::history_location).
+    pub async fn get_cycles(&self) -> Result<Cycles, Error> {
+        self.get_cycles_from(&self.history_location()).await
+    }
+
+    /// Get the AIRAC cycles this eAIP has published, from the page at the given location. This
+    /// is useful if the eAIP doesn't publish its history at [`history_location`](Self::history_location).
+    pub async fn get_cycles_from(&self, location: &str) -> Result<Cycles, Error> {
+        let data = self.get_file(location).await?;
+        Cycles::parse(&String::from_utf8_lossy(&data))
     }
 
     /// Generate a URL within this eAIP
@@ -61,14 +187,30 @@ impl EAIP {
         format!(
             "{}{}",
             self.base_uri,
-            generate_location_with_airac_and_offseet(
-                airac,
-                self.publish_offset,
-                self.country_code.clone(),
-                part,
-                self.locale.clone(),
-                typ
-            )
+            self.generate_location(airac, part, typ)
         )
     }
+
+    /// Generate the location of a page within this eAIP, relative to the base URI.
+    pub fn generate_location(&self, airac: AIRAC, part: Part, typ: EAIPType) -> String {
+        generate_location_with_airac_and_offseet(
+            airac,
+            self.publish_offset,
+            self.country_code.clone(),
+            part,
+            self.locale.clone(),
+            typ,
+        )
+    }
+
+    /// Generate the location of the package for an AIRAC within this eAIP, relative to the base
+    /// URI. Every page and file for that AIRAC is found within this location.
+    pub fn generate_package_location(&self, airac: AIRAC) -> String {
+        let location = self.generate_location(airac, Part::Raw(String::new()), EAIPType::HTML);
+        let end = location[1..]
+            .find('/')
+            .map(|i| i + 1)
+            .unwrap_or(location.len());
+        location[..end].to_string()
+    }
 }
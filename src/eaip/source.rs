@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::path::PathBuf;
+
+use airac::AIRAC;
+use async_trait::async_trait;
+
+use super::EAIP;
+use crate::error::{Error, Result};
+use crate::parts::*;
+
+/// A source of eAIP pages. An [`EAIP`] asks its page source for every page it needs, so
+/// replacing the source allows the whole fetch and parse pipeline to run without network access.
+#[async_trait]
+pub trait PageSource: Debug + Send + Sync {
+    /// Get a page from the given eAIP for the specified AIRAC.
+    async fn get_page(
+        &self,
+        eaip: &EAIP,
+        airac: AIRAC,
+        part: Part,
+        typ: EAIPType,
+    ) -> Result<String>;
+
+    /// Get any file from the given eAIP by its location relative to the base URI, for example
+    /// a chart linked from a page.
+    async fn get_file(&self, eaip: &EAIP, location: &str) -> Result<Vec<u8>>;
+}
+
+/// A [`PageSource`] that reads pages from a local directory. The directory must follow the
+/// same layout as the online eAIP, i.e. `<root>/YYYY-MM-DD-AIRAC/html/eAIP/...`. Only files
+/// that don't exist are reported as missing; any other error reading them is an
+/// [`Error::IOError`].
+#[derive(Debug, Clone)]
+pub struct DirectorySource {
+    root: PathBuf,
+}
+
+impl DirectorySource {
+    /// Create a new page source reading from the directory `root`.
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The root directory of this page source.
+    pub fn root(&self) -> &PathBuf {
+        &self.root
+    }
+}
+
+#[async_trait]
+impl PageSource for DirectorySource {
+    async fn get_page(
+        &self,
+        eaip: &EAIP,
+        airac: AIRAC,
+        part: Part,
+        typ: EAIPType,
+    ) -> Result<String> {
+        let location = eaip.generate_location(airac.clone(), part.clone(), typ);
+        let path = self.root.join(location.trim_start_matches('/'));
+        log::debug!("Reading page: {}", path.display());
+        std::fs::read_to_string(path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => Error::EAIPMissingPage(airac, part, typ),
+            _ => Error::IOError(e),
+        })
+    }
+
+    async fn get_file(&self, _eaip: &EAIP, location: &str) -> Result<Vec<u8>> {
+        let path = self.root.join(location.trim_start_matches('/'));
+        log::debug!("Reading file: {}", path.display());
+        std::fs::read(path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => Error::EAIPMissingFile(location.to_string()),
+            _ => Error::IOError(e),
+        })
+    }
+}
+
+/// A [`PageSource`] that serves pages held in memory, keyed by their location within the eAIP.
+#[derive(Debug, Default, Clone)]
+pub struct MemorySource {
+    pages: HashMap<String, Vec<u8>>,
+}
+
+impl MemorySource {
+    /// Create a new, empty, in-memory page source.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a page at the given location, as generated by [`EAIP::generate_location`].
+    pub fn insert<S: Into<String>>(&mut self, location: S, content: S) {
+        self.pages
+            .insert(location.into(), content.into().into_bytes());
+    }
+
+    /// Add a file, such as a chart, at the given location.
+    pub fn insert_file<S: Into<String>>(&mut self, location: S, content: Vec<u8>) {
+        self.pages.insert(location.into(), content);
+    }
+
+    /// Add a page for the given eAIP, AIRAC, part and type.
+    pub fn insert_page<S: Into<String>>(
+        &mut self,
+        eaip: &EAIP,
+        airac: AIRAC,
+        part: Part,
+        typ: EAIPType,
+        content: S,
+    ) {
+        self.pages.insert(
+            eaip.generate_location(airac, part, typ),
+            content.into().into_bytes(),
+        );
+    }
+}
+
+#[async_trait]
+impl PageSource for MemorySource {
+    async fn get_page(
+        &self,
+        eaip: &EAIP,
+        airac: AIRAC,
+        part: Part,
+        typ: EAIPType,
+    ) -> Result<String> {
+        let location = eaip.generate_location(airac.clone(), part.clone(), typ);
+        self.pages
+            .get(&location)
+            .map(|data| String::from_utf8_lossy(data).to_string())
+            .ok_or(Error::EAIPMissingPage(airac, part, typ))
+    }
+
+    async fn get_file(&self, _eaip: &EAIP, location: &str) -> Result<Vec<u8>> {
+        self.pages
+            .get(location)
+            .cloned()
+            .ok_or_else(|| Error::EAIPMissingFile(location.to_string()))
+    }
+}
@@ -7,13 +7,23 @@ use crate::parts::{EAIPType, Part};
 /// A result type, using the [`Error`] enum.
 pub type Result<T> = std::result::Result<T, Error>;
 
-/// Error type for eAIP errors
+/// Error type for eAIP errors. Some variants only exist with certain features enabled, so this
+/// can't be matched exhaustively.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// An error fetching data from an eAIP
     EAIPFetchError(reqwest::Error),
     /// A page is missing from the eAIP
     EAIPMissingPage(AIRAC, Part, EAIPType),
+    /// A file is missing from the eAIP. The argument is its location.
+    EAIPMissingFile(String),
+    /// The eAIP server reported a temporary failure, with the given HTTP status, and retrying
+    /// didn't help
+    EAIPUnavailable(u16),
+    /// The eAIP server refused a request with the given HTTP status, for example because access
+    /// is forbidden
+    EAIPRequestFailed(u16),
     /// An error parsing the base_url of an EAIP when canonicalising URLs
     EAIPInvalidBaseURL(url::ParseError),
     /// An error while canonicalising URLs in joining the base URL to the chart URL
@@ -22,10 +32,27 @@ pub enum Error {
     CannotScrapeData(&'static str),
     /// Some data cannot be parsed. The argument says what data.
     ParseError(&'static str, String),
+    /// An error reading or writing local files
+    IOError(std::io::Error),
+    /// An error reading or writing a navigation database
+    #[cfg(feature = "sqlite")]
+    SQLiteError(rusqlite::Error),
 }
 
 impl std::error::Error for Error {}
 
+impl Error {
+    /// Is this error a temporary failure, such that trying again later may succeed? This is not
+    /// the case if a page is genuinely missing from the eAIP.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::EAIPFetchError(e) => e.is_timeout() || e.is_connect() || e.is_body(),
+            Self::EAIPUnavailable(_) => true,
+            _ => false,
+        }
+    }
+}
+
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -37,6 +64,15 @@ impl std::fmt::Display for Error {
                 "The AIP does not have a page for {} {} {}",
                 airac, part, typ
             ),
+            Self::EAIPMissingFile(location) => {
+                write!(f, "The AIP does not have a file at {}", location)
+            }
+            Self::EAIPUnavailable(status) => {
+                write!(f, "The AIP is temporarily unavailable (HTTP {})", status)
+            }
+            Self::EAIPRequestFailed(status) => {
+                write!(f, "The AIP refused the request (HTTP {})", status)
+            }
             Self::EAIPInvalidBaseURL(e) => {
                 write!(f, "There was an error parsing the AIP base URL: {}", e)
             }
@@ -49,6 +85,17 @@ impl std::fmt::Display for Error {
             Self::ParseError(what, thing) => {
                 write!(f, "The {} cannot be parsed ({:?}).", what, thing)
             }
+            Self::IOError(e) => {
+                write!(f, "There was an error accessing local files: {}", e)
+            }
+            #[cfg(feature = "sqlite")]
+            Self::SQLiteError(e) => {
+                write!(
+                    f,
+                    "There was an error accessing the navigation database: {}",
+                    e
+                )
+            }
         }
     }
 }
@@ -58,3 +105,16 @@ impl From<reqwest::Error> for Error {
         Self::EAIPFetchError(e)
     }
 }
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Self::IOError(e)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for Error {
+    fn from(e: rusqlite::Error) -> Self {
+        Self::SQLiteError(e)
+    }
+}
@@ -0,0 +1,628 @@
+use std::fmt::{Display, Write};
+
+use airac::AIRAC;
+use regex::Regex;
+use sha2::{Digest, Sha256};
+
+use super::sct::UPPER_AIRSPACE_FL;
+use super::xplane::{FL_RE, FT_RE};
+use super::{is_high_segment, segments, Fixes};
+use crate::prelude::*;
+
+/// The spatial reference system of every position, with latitude before longitude.
+const SRS_NAME: &str = "urn:ogc:def:crs:EPSG::4326";
+
+/// A stable UUID for a feature, derived from its type and designator, so that the same feature
+/// has the same identifier in every cycle.
+fn uuid(feature: &str, key: &str) -> String {
+    let hash = Sha256::digest(format!("{}:{}", feature, key).as_bytes());
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&hash[..16]);
+    // Mark as a custom UUID (version 8, RFC 9562 variant), as version 5 is reserved for SHA-1.
+    bytes[6] = (bytes[6] & 0x0f) | 0x80;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = bytes
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// The key used for the identifiers of a navaid. Navaid identifiers aren't unique, so this
+/// includes the name.
+fn navaid_key(navaid: &NavAid) -> String {
+    format!("{} {}", navaid.id, navaid.name)
+}
+
+/// Escape a string for use in XML text or an attribute value.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Convert an airway limit, such as `FL 195`, `5500 FT ALT` or `UNL`, to an AIXM value, unit of
+/// measurement and vertical reference.
+fn vertical_limit(limit: &str) -> Option<(String, &'static str, &'static str)> {
+    if let Some(caps) = FL_RE.captures(limit) {
+        Some((caps[1].to_string(), "FL", "STD"))
+    } else if let Some(caps) = FT_RE.captures(limit) {
+        let reference = if limit.contains("AGL") || limit.contains("SFC") {
+            "SFC"
+        } else {
+            "MSL"
+        };
+        Some((caps[1].to_string(), "FT", reference))
+    } else if limit.contains("UNL") {
+        Some(("UNL".to_string(), "OTHER", "STD"))
+    } else if limit.contains("GND") || limit.contains("SFC") {
+        Some(("GND".to_string(), "OTHER", "SFC"))
+    } else {
+        None
+    }
+}
+
+/// An AIXM 5.1 `AIXMBasicMessage` built from eAIP data. This is formatted as XML with
+/// [`Display`].
+///
+/// Every feature has a single `BASELINE` time slice, valid for the AIRAC the message was created
+/// for. Feature identifiers are UUIDs derived from the feature type and designator, so they are
+/// the same in every cycle.
+///
+/// Navaids become `VOR`, `DME`, `NDB` or `TACAN` equipment features, along with a `Navaid`
+/// feature grouping the equipment (a VOR/DME has both a `VOR` and a `DME`). Intersections become
+/// `DesignatedPoint` features, airways become a `Route` feature with a `RouteSegment` feature for
+/// each segment, and airports become `AirportHeliport` features with the ARP and elevation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BasicMessage {
+    airac: AIRAC,
+    members: Vec<String>,
+    ids: usize,
+}
+
+impl BasicMessage {
+    /// Create a new, empty, message for the given AIRAC.
+    pub fn new(airac: AIRAC) -> Self {
+        Self {
+            airac,
+            members: Vec::new(),
+            ids: 0,
+        }
+    }
+
+    /// The number of features in this message.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Is this message empty?
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// A new `gml:id`, unique within this message.
+    fn gml_id(&mut self) -> String {
+        self.ids += 1;
+        format!("ID_{}", self.ids)
+    }
+
+    /// Add a feature, with a time slice valid for the AIRAC and the given properties.
+    fn add_feature(&mut self, feature: &str, id: &str, properties: &str) {
+        let begin = format!("{}T00:00:00Z", self.airac.starts().format("%Y-%m-%d"));
+        let end = format!("{}T00:00:00Z", self.airac.ends().format("%Y-%m-%d"));
+        let (slice_id, valid_id, lifetime_id) = (self.gml_id(), self.gml_id(), self.gml_id());
+        let mut out = String::new();
+        writeln!(out, r#"<aixm:{} gml:id="uuid.{}">"#, feature, id).unwrap();
+        writeln!(
+            out,
+            r#"<gml:identifier codeSpace="urn:uuid:">{}</gml:identifier>"#,
+            id
+        )
+        .unwrap();
+        writeln!(out, "<aixm:timeSlice>").unwrap();
+        writeln!(out, r#"<aixm:{}TimeSlice gml:id="{}">"#, feature, slice_id).unwrap();
+        writeln!(
+            out,
+            r#"<gml:validTime><gml:TimePeriod gml:id="{}"><gml:beginPosition>{}</gml:beginPosition><gml:endPosition>{}</gml:endPosition></gml:TimePeriod></gml:validTime>"#,
+            valid_id, begin, end
+        )
+        .unwrap();
+        writeln!(out, "<aixm:interpretation>BASELINE</aixm:interpretation>").unwrap();
+        writeln!(out, "<aixm:sequenceNumber>1</aixm:sequenceNumber>").unwrap();
+        writeln!(out, "<aixm:correctionNumber>0</aixm:correctionNumber>").unwrap();
+        writeln!(
+            out,
+            r#"<aixm:featureLifetime><gml:TimePeriod gml:id="{}"><gml:beginPosition>{}</gml:beginPosition><gml:endPosition indeterminatePosition="unknown"/></gml:TimePeriod></aixm:featureLifetime>"#,
+            lifetime_id, begin
+        )
+        .unwrap();
+        out += properties;
+        writeln!(out, "</aixm:{}TimeSlice>", feature).unwrap();
+        writeln!(out, "</aixm:timeSlice>").unwrap();
+        write!(out, "</aixm:{}>", feature).unwrap();
+        self.members.push(out);
+    }
+
+    /// An `ElevatedPoint` at the given position, or a `Point` if there is no elevation.
+    fn point(&mut self, latitude: f64, longitude: f64, elevation: Option<isize>) -> String {
+        let id = self.gml_id();
+        match elevation {
+            Some(elevation) => format!(
+                r#"<aixm:ElevatedPoint gml:id="{}" srsName="{}"><gml:pos>{} {}</gml:pos><aixm:elevation uom="FT">{}</aixm:elevation></aixm:ElevatedPoint>"#,
+                id, SRS_NAME, latitude, longitude, elevation
+            ),
+            None => format!(
+                r#"<aixm:Point gml:id="{}" srsName="{}"><gml:pos>{} {}</gml:pos></aixm:Point>"#,
+                id, SRS_NAME, latitude, longitude
+            ),
+        }
+    }
+
+    /// Add navaids as equipment features, each with a `Navaid` feature grouping the equipment.
+    pub fn add_navaids(&mut self, navaids: &[NavAid]) -> &mut Self {
+        for navaid in navaids {
+            let key = navaid_key(navaid);
+            let (navaid_type, equipment): (&str, &[&str]) = match navaid.kind {
+                NavAidKind::VOR => ("VOR", &["VOR"]),
+                NavAidKind::DME => ("DME", &["DME"]),
+                NavAidKind::VORDME => ("VOR_DME", &["VOR", "DME"]),
+                NavAidKind::NDB => ("NDB", &["NDB"]),
+                NavAidKind::TACAN => ("TACAN", &["TACAN"]),
+                NavAidKind::LOC => ("LOC", &["Localizer"]),
+                NavAidKind::GP => ("ILS", &["Glidepath"]),
+                NavAidKind::OM | NavAidKind::MM | NavAidKind::IM => ("MKR", &["MarkerBeacon"]),
+                NavAidKind::Locator => ("NDB", &["NDB"]),
+            };
+            let mut components = String::new();
+            for feature in equipment {
+                let id = uuid(feature, &key);
+                let location =
+                    self.point(navaid.latitude, navaid.longitude, Some(navaid.elevation));
+                let mut properties = String::new();
+                writeln!(
+                    properties,
+                    "<aixm:designator>{}</aixm:designator>",
+                    escape(&navaid.id)
+                )
+                .unwrap();
+                writeln!(
+                    properties,
+                    "<aixm:name>{}</aixm:name>",
+                    escape(&navaid.name)
+                )
+                .unwrap();
+                writeln!(properties, "<aixm:location>{}</aixm:location>", location).unwrap();
+                match *feature {
+                    "VOR" | "Localizer" | "Glidepath" | "MarkerBeacon" => writeln!(
+                        properties,
+                        r#"<aixm:frequency uom="MHZ">{:.2}</aixm:frequency>"#,
+                        navaid.frequency_khz as f64 / 1000f64
+                    )
+                    .unwrap(),
+                    "DME" => writeln!(
+                        properties,
+                        r#"<aixm:ghostFrequency uom="MHZ">{:.2}</aixm:ghostFrequency>"#,
+                        navaid.frequency_khz as f64 / 1000f64
+                    )
+                    .unwrap(),
+                    "NDB" => writeln!(
+                        properties,
+                        r#"<aixm:frequency uom="KHZ">{}</aixm:frequency>"#,
+                        navaid.frequency_khz
+                    )
+                    .unwrap(),
+                    _ => (),
+                }
+                if let Some(course) = navaid.course.filter(|_| *feature == "Localizer") {
+                    writeln!(
+                        properties,
+                        "<aixm:magneticBearing>{}</aixm:magneticBearing>",
+                        course
+                    )
+                    .unwrap();
+                }
+                if let Some(angle) = navaid.glidepath_angle.filter(|_| *feature == "Glidepath") {
+                    writeln!(properties, "<aixm:slope>{}</aixm:slope>", angle).unwrap();
+                }
+                self.add_feature(feature, &id, &properties);
+
+                let component_id = self.gml_id();
+                writeln!(
+                    components,
+                    r##"<aixm:navaidEquipment><aixm:NavaidComponent gml:id="{}"><aixm:theNavaidEquipment xlink:href="urn:uuid:{}"/></aixm:NavaidComponent></aixm:navaidEquipment>"##,
+                    component_id, id
+                )
+                .unwrap();
+            }
+
+            let location = self.point(navaid.latitude, navaid.longitude, Some(navaid.elevation));
+            let mut properties = String::new();
+            writeln!(properties, "<aixm:type>{}</aixm:type>", navaid_type).unwrap();
+            writeln!(
+                properties,
+                "<aixm:designator>{}</aixm:designator>",
+                escape(&navaid.id)
+            )
+            .unwrap();
+            writeln!(
+                properties,
+                "<aixm:name>{}</aixm:name>",
+                escape(&navaid.name)
+            )
+            .unwrap();
+            writeln!(properties, "<aixm:location>{}</aixm:location>", location).unwrap();
+            properties += &components;
+            self.add_feature("Navaid", &uuid("Navaid", &key), &properties);
+        }
+        self
+    }
+
+    /// Add intersections as `DesignatedPoint` features.
+    pub fn add_intersections(&mut self, intersections: &[Intersection]) -> &mut Self {
+        for intersection in intersections {
+            let location = self.point(intersection.latitude, intersection.longitude, None);
+            let mut properties = String::new();
+            writeln!(
+                properties,
+                "<aixm:designator>{}</aixm:designator>",
+                escape(&intersection.designator)
+            )
+            .unwrap();
+            writeln!(properties, "<aixm:type>ICAO</aixm:type>").unwrap();
+            writeln!(properties, "<aixm:location>{}</aixm:location>", location).unwrap();
+            self.add_feature(
+                "DesignatedPoint",
+                &uuid("DesignatedPoint", &intersection.designator),
+                &properties,
+            );
+        }
+        self
+    }
+
+    /// Add airways as `Route` features, with a `RouteSegment` feature for each segment. Segments
+    /// with a waypoint that can't be found in `navaids` or `intersections` are left out.
+    ///
+    /// Segments in the upper airspace, from FL 245, are upper segments and all others are lower
+    /// segments. Only if the limits are missing are airways with a designator starting with `U`
+    /// upper routes.
+    pub fn add_airways(
+        &mut self,
+        airways: &[Airway],
+        navaids: &[NavAid],
+        intersections: &[Intersection],
+    ) -> &mut Self {
+        let designator_re = Regex::new(r"^([KUSTD]?)([A-Z])(\d+)([A-Z]?)$").unwrap();
+        let fixes = Fixes::new(navaids, intersections);
+        for airway in airways {
+            let route_id = uuid("Route", &airway.designator);
+            let mut properties = String::new();
+            if let Some(caps) = designator_re.captures(&airway.designator) {
+                if !caps[1].is_empty() {
+                    writeln!(
+                        properties,
+                        "<aixm:designatorPrefix>{}</aixm:designatorPrefix>",
+                        &caps[1]
+                    )
+                    .unwrap();
+                }
+                writeln!(
+                    properties,
+                    "<aixm:designatorSecondLetter>{}</aixm:designatorSecondLetter>",
+                    &caps[2]
+                )
+                .unwrap();
+                writeln!(
+                    properties,
+                    "<aixm:designatorNumber>{}</aixm:designatorNumber>",
+                    &caps[3]
+                )
+                .unwrap();
+            }
+            writeln!(
+                properties,
+                "<aixm:name>{}</aixm:name>",
+                escape(&airway.designator)
+            )
+            .unwrap();
+            writeln!(properties, "<aixm:type>ATS</aixm:type>").unwrap();
+            self.add_feature("Route", &route_id, &properties);
+
+            for (from_wp, from, to_wp, to) in segments(airway, &fixes) {
+                let level = if is_high_segment(airway, from_wp, UPPER_AIRSPACE_FL) {
+                    "UPPER"
+                } else {
+                    "LOWER"
+                };
+                let point = |this: &mut Self, designator: &str, fix: super::Fix| {
+                    let choice = match fix.navaid {
+                        Some(navaid) => format!(
+                            r#"<aixm:pointChoice_navaidSystem xlink:href="urn:uuid:{}"/>"#,
+                            uuid("Navaid", &navaid_key(navaid))
+                        ),
+                        None => format!(
+                            r#"<aixm:pointChoice_fixDesignatedPoint xlink:href="urn:uuid:{}"/>"#,
+                            uuid("DesignatedPoint", designator)
+                        ),
+                    };
+                    format!(
+                        r#"<aixm:EnRouteSegmentPoint gml:id="{}">{}</aixm:EnRouteSegmentPoint>"#,
+                        this.gml_id(),
+                        choice
+                    )
+                };
+                let start = point(self, &from_wp.designator, from);
+                let end = point(self, &to_wp.designator, to);
+                let curve_id = self.gml_id();
+
+                let mut properties = String::new();
+                writeln!(properties, "<aixm:level>{}</aixm:level>", level).unwrap();
+                for (element, limit) in [
+                    ("upperLimit", &from_wp.upper_limit),
+                    ("lowerLimit", &from_wp.lower_limit),
+                ] {
+                    if let Some((value, uom, reference)) = vertical_limit(limit) {
+                        writeln!(
+                            properties,
+                            r#"<aixm:{0} uom="{1}">{2}</aixm:{0}><aixm:{0}Reference>{3}</aixm:{0}Reference>"#,
+                            element, uom, value, reference
+                        )
+                        .unwrap();
+                    }
+                }
+                writeln!(properties, "<aixm:start>{}</aixm:start>", start).unwrap();
+                writeln!(properties, "<aixm:end>{}</aixm:end>", end).unwrap();
+                writeln!(
+                    properties,
+                    r#"<aixm:curveExtent><aixm:Curve gml:id="{}" srsName="{}"><gml:segments><gml:GeodesicString><gml:posList>{} {} {} {}</gml:posList></gml:GeodesicString></gml:segments></aixm:Curve></aixm:curveExtent>"#,
+                    curve_id, SRS_NAME, from.latitude, from.longitude, to.latitude, to.longitude
+                )
+                .unwrap();
+                writeln!(
+                    properties,
+                    r#"<aixm:routeFormed xlink:href="urn:uuid:{}"/>"#,
+                    route_id
+                )
+                .unwrap();
+                let key = format!(
+                    "{} {} {}",
+                    airway.designator, from_wp.designator, to_wp.designator
+                );
+                self.add_feature("RouteSegment", &uuid("RouteSegment", &key), &properties);
+            }
+        }
+        self
+    }
+
+    /// Add airports as `AirportHeliport` features, with the ARP and field elevation.
+    pub fn add_airports(&mut self, airports: &[Airport]) -> &mut Self {
+        for airport in airports {
+            let arp = self.point(airport.latitude, airport.longitude, Some(airport.elevation));
+            let mut properties = String::new();
+            writeln!(
+                properties,
+                "<aixm:designator>{}</aixm:designator>",
+                escape(&airport.icao)
+            )
+            .unwrap();
+            writeln!(
+                properties,
+                "<aixm:name>{}</aixm:name>",
+                escape(&airport.name)
+            )
+            .unwrap();
+            writeln!(
+                properties,
+                "<aixm:locationIndicatorICAO>{}</aixm:locationIndicatorICAO>",
+                escape(&airport.icao)
+            )
+            .unwrap();
+            writeln!(properties, "<aixm:type>AD</aixm:type>").unwrap();
+            writeln!(
+                properties,
+                r#"<aixm:fieldElevation uom="FT">{}</aixm:fieldElevation>"#,
+                airport.elevation
+            )
+            .unwrap();
+            writeln!(properties, "<aixm:ARP>{}</aixm:ARP>", arp).unwrap();
+            self.add_feature(
+                "AirportHeliport",
+                &uuid("AirportHeliport", &airport.icao),
+                &properties,
+            );
+        }
+        self
+    }
+}
+
+impl Display for BasicMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            f,
+            r#"<message:AIXMBasicMessage xmlns:message="http://www.aixm.aero/schema/5.1/message" xmlns:aixm="http://www.aixm.aero/schema/5.1" xmlns:gml="http://www.opengis.net/gml/3.2" xmlns:xlink="http://www.w3.org/1999/xlink" gml:id="MSG_{}">"#,
+            self.airac
+        )?;
+        for member in &self.members {
+            writeln!(f, "<message:hasMember>\n{}\n</message:hasMember>", member)?;
+        }
+        writeln!(f, "</message:AIXMBasicMessage>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uuid() {
+        let id = uuid("Navaid", "ADN");
+        assert_eq!(id, uuid("Navaid", "ADN"));
+        assert_ne!(id, uuid("DesignatedPoint", "ADN"));
+        assert_eq!(36, id.len());
+        assert_eq!(Some('8'), id.chars().nth(14));
+        assert!(matches!(id.chars().nth(19), Some('8' | '9' | 'a' | 'b')));
+    }
+
+    #[test]
+    fn test_vertical_limit() {
+        assert_eq!(
+            Some(("195".to_string(), "FL", "STD")),
+            vertical_limit("FL 195")
+        );
+        assert_eq!(
+            Some(("5500".to_string(), "FT", "MSL")),
+            vertical_limit("5500 FT ALT")
+        );
+        assert_eq!(
+            Some(("UNL".to_string(), "OTHER", "STD")),
+            vertical_limit("UNL")
+        );
+        assert_eq!(None, vertical_limit(""));
+    }
+
+    #[test]
+    fn test_basic_message() {
+        let navaids = vec![NavAid {
+            id: "ADN".to_string(),
+            name: "ABERDEEN & DYCE".to_string(),
+            kind: NavAidKind::VORDME,
+            frequency_khz: 114300,
+            latitude: 57.31,
+            longitude: -2.27,
+            elevation: 600,
+            ..Default::default()
+        }];
+        let intersections = vec![Intersection {
+            designator: "ABBEW".to_string(),
+            latitude: 57.5,
+            longitude: -2.0,
+        }];
+        let airways = vec![Airway {
+            designator: "UL9".to_string(),
+            waypoints: vec![
+                AirwayWaypoint {
+                    designator: "ADN".to_string(),
+                    lower_limit: "FL 245".to_string(),
+                    upper_limit: "UNL".to_string(),
+                },
+                AirwayWaypoint {
+                    designator: "ABBEW".to_string(),
+                    ..Default::default()
+                },
+            ],
+        }];
+        let airports = vec![Airport {
+            icao: "EGPD".to_string(),
+            name: "ABERDEEN".to_string(),
+            latitude: 57.2,
+            longitude: -2.2,
+            elevation: 215,
+            ..Default::default()
+        }];
+
+        let mut message = BasicMessage::new(AIRAC::from_ymd(2022, 5, 19));
+        message
+            .add_navaids(&navaids)
+            .add_intersections(&intersections)
+            .add_airways(&airways, &navaids, &intersections)
+            .add_airports(&airports);
+        // VOR, DME and Navaid; DesignatedPoint; Route and RouteSegment; AirportHeliport.
+        assert_eq!(7, message.len());
+
+        let xml = message.to_string();
+        assert!(xml.contains("<gml:beginPosition>2022-05-19T00:00:00Z</gml:beginPosition><gml:endPosition>2022-06-16T00:00:00Z</gml:endPosition>"));
+        assert!(xml.contains("<aixm:name>ABERDEEN &amp; DYCE</aixm:name>"));
+        assert!(xml.contains(r#"<aixm:frequency uom="MHZ">114.30</aixm:frequency>"#));
+        assert!(xml.contains("<aixm:type>VOR_DME</aixm:type>"));
+        assert!(xml.contains("<aixm:designatorPrefix>U</aixm:designatorPrefix>"));
+        assert!(xml.contains(r#"<aixm:upperLimit uom="OTHER">UNL</aixm:upperLimit>"#));
+        assert!(xml.contains(r#"<aixm:lowerLimit uom="FL">245</aixm:lowerLimit>"#));
+        assert!(xml.contains(&format!(
+            r#"<aixm:pointChoice_navaidSystem xlink:href="urn:uuid:{}"/>"#,
+            uuid("Navaid", "ADN ABERDEEN & DYCE")
+        )));
+        assert!(xml.contains(&format!(
+            r#"<aixm:pointChoice_fixDesignatedPoint xlink:href="urn:uuid:{}"/>"#,
+            uuid("DesignatedPoint", "ABBEW")
+        )));
+        assert!(xml.contains(
+            r#"<gml:pos>57.2 -2.2</gml:pos><aixm:elevation uom="FT">215</aixm:elevation>"#
+        ));
+        assert!(xml.trim_end().ends_with("</message:AIXMBasicMessage>"));
+    }
+
+    #[test]
+    fn test_route_levels() {
+        let airway = |designator: &str, lower: &str, upper: &str| Airway {
+            designator: designator.to_string(),
+            waypoints: vec![
+                AirwayWaypoint {
+                    designator: "ADN".to_string(),
+                    lower_limit: lower.to_string(),
+                    upper_limit: upper.to_string(),
+                },
+                AirwayWaypoint {
+                    designator: "ABBEW".to_string(),
+                    ..Default::default()
+                },
+            ],
+        };
+        let navaids = vec![NavAid {
+            id: "ADN".to_string(),
+            ..Default::default()
+        }];
+        let intersections = vec![Intersection {
+            designator: "ABBEW".to_string(),
+            ..Default::default()
+        }];
+        let level = |airway: Airway| {
+            let mut message = BasicMessage::new(AIRAC::from_ymd(2022, 5, 19));
+            message.add_airways(&[airway], &navaids, &intersections);
+            let xml = message.to_string();
+            let start = xml.find("<aixm:level>").unwrap() + "<aixm:level>".len();
+            xml[start..start + 5].to_string()
+        };
+        assert_eq!("UPPER", level(airway("L9", "FL 255", "FL 460")));
+        assert_eq!("LOWER", level(airway("UL10", "FL 75", "FL 195")));
+        assert_eq!("UPPER", level(airway("UL11", "", "")));
+    }
+
+    #[test]
+    fn test_parsed_coordinates() {
+        let navaids = Navaids::parse(
+            r#"<html><body><table><tbody><tr>
+            <td><strong>ABERDEEN</strong><p class="line">VOR/DME</p></td>
+            <td>ADN</td><td>114.300 MHz</td><td>H24</td>
+            <td>571839.00N 0021602.00W</td><td>600 FT</td>
+            </tr></tbody></table></body></html>"#,
+        )
+        .unwrap();
+        let mut message = BasicMessage::new(AIRAC::from_ymd(2022, 5, 19));
+        message.add_navaids(&navaids);
+        let xml = message.to_string();
+        let start = xml.find("<gml:pos>").unwrap() + "<gml:pos>".len();
+        let end = start + xml[start..].find("</gml:pos>").unwrap();
+        let position = xml[start..end]
+            .split(' ')
+            .map(|v| v.parse::<f64>().unwrap())
+            .collect::<Vec<_>>();
+        assert!((position[0] - 57.310833).abs() < 1e-6);
+        assert!((position[1] + 2.267222).abs() < 1e-6);
+    }
+}
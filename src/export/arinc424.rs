@@ -0,0 +1,622 @@
+use std::fmt::Display;
+
+use airac::AIRAC;
+
+use super::sct::UPPER_AIRSPACE_FL;
+use super::xplane::flight_level;
+use super::{is_high_segment, Fixes};
+use crate::prelude::*;
+
+/// The length of an ARINC 424 record.
+pub const RECORD_LENGTH: usize = 132;
+
+/// A fixed-width ARINC 424 record. Columns are numbered from 1, as in the specification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Record([u8; RECORD_LENGTH]);
+
+impl Record {
+    /// Create a standard record for the given customer area, section and subsection.
+    fn new(area: &str, section: char, subsection: char) -> Self {
+        let mut record = Self([b' '; RECORD_LENGTH]);
+        record.set(1, 1, "S");
+        record.set(2, 3, area);
+        record.set(5, 1, &section.to_string());
+        record.set(6, 1, &subsection.to_string());
+        record
+    }
+
+    /// Set the field starting at column `col`, `len` columns long. The value is left-aligned and
+    /// truncated to fit.
+    fn set(&mut self, col: usize, len: usize, value: &str) {
+        for (i, b) in value.bytes().take(len).enumerate() {
+            self.0[col - 1 + i] = if b.is_ascii() { b } else { b'?' };
+        }
+    }
+
+    /// Get the field starting at column `col`, `len` columns long, with trailing spaces removed.
+    fn get(&self, col: usize, len: usize) -> String {
+        String::from_utf8_lossy(&self.0[col - 1..col - 1 + len])
+            .trim_end()
+            .to_string()
+    }
+
+    fn parse(line: &str) -> Result<Self> {
+        if line.len() != RECORD_LENGTH || !line.is_ascii() {
+            return Err(Error::ParseError("ARINC 424 record", line.to_string()));
+        }
+        let mut record = Self([b' '; RECORD_LENGTH]);
+        record.0.copy_from_slice(line.as_bytes());
+        Ok(record)
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(&self.0))
+    }
+}
+
+/// Encode a latitude as `NDDMMSSss`.
+fn encode_latitude(latitude: f64) -> String {
+    let hemisphere = if latitude < 0f64 { 'S' } else { 'N' };
+    format!("{}{}", hemisphere, encode_dms(latitude.abs(), 2))
+}
+
+/// Encode a longitude as `EDDDMMSSss`.
+fn encode_longitude(longitude: f64) -> String {
+    let hemisphere = if longitude < 0f64 { 'W' } else { 'E' };
+    format!("{}{}", hemisphere, encode_dms(longitude.abs(), 3))
+}
+
+fn encode_dms(value: f64, degree_digits: usize) -> String {
+    let hundredths = (value * 360_000f64).round() as u64;
+    let degrees = hundredths / 360_000;
+    let minutes = hundredths % 360_000 / 6_000;
+    let seconds = hundredths % 6_000;
+    format!(
+        "{:0width$}{:02}{:04}",
+        degrees,
+        minutes,
+        seconds,
+        width = degree_digits
+    )
+}
+
+/// Decode a latitude or longitude encoded with [`encode_latitude`] or [`encode_longitude`].
+fn decode_coordinate(value: &str) -> Result<f64> {
+    let err = || Error::ParseError("ARINC 424 coordinate", value.to_string());
+    let hemisphere = value.chars().next().ok_or_else(err)?;
+    let digits = &value[1..];
+    let degree_digits = digits.len().checked_sub(6).ok_or_else(err)?;
+    let degrees = digits[..degree_digits].parse::<f64>().map_err(|_| err())?;
+    let minutes = digits[degree_digits..degree_digits + 2]
+        .parse::<f64>()
+        .map_err(|_| err())?;
+    let seconds = digits[degree_digits + 2..]
+        .parse::<f64>()
+        .map_err(|_| err())?
+        / 100f64;
+    let value = degrees + minutes / 60f64 + seconds / 3600f64;
+    match hemisphere {
+        'N' | 'E' => Ok(value),
+        'S' | 'W' => Ok(-value),
+        _ => Err(err()),
+    }
+}
+
+/// Encode an airway limit as a flight level (`FL195`) or altitude in feet (`05500`).
+fn encode_altitude(limit: &str) -> String {
+    if limit.contains("FL") {
+        if let Some(fl) = flight_level(limit) {
+            return format!("FL{:03}", fl);
+        }
+    }
+    match flight_level(limit) {
+        Some(600) if limit.contains("UNL") => "UNLTD".to_string(),
+        Some(fl) => format!("{:05}", fl * 100),
+        None => String::new(),
+    }
+}
+
+/// Decode an altitude encoded with [`encode_altitude`] back to an airway limit.
+fn decode_altitude(value: &str) -> String {
+    if let Some(fl) = value.strip_prefix("FL") {
+        format!("FL {}", fl.trim_start_matches('0'))
+    } else if value == "UNLTD" {
+        "UNL".to_string()
+    } else if let Ok(ft) = value.parse::<usize>() {
+        format!("{} FT ALT", ft)
+    } else {
+        String::new()
+    }
+}
+
+/// An encoder for ARINC 424 records. This is formatted as a file of records, one per line, with
+/// [`Display`].
+///
+/// VHF navaids are written as `D` records, NDBs as `DB` records, intersections as `EA` enroute
+/// waypoint records, each waypoint of an airway as an `ER` record and airports as `PA` airport
+/// reference point records. Names that don't fit in a primary record are written in full to a
+/// continuation record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arinc424 {
+    area: String,
+    region: String,
+    airac: AIRAC,
+    records: Vec<Record>,
+}
+
+impl Arinc424 {
+    /// Create a new encoder. `region` is the ICAO region the data is in, such as `EG`, and
+    /// `airac` is written as the cycle of every record. The customer area defaults to `EUR`.
+    pub fn new<S: Into<String>>(region: S, airac: AIRAC) -> Self {
+        Self {
+            area: "EUR".to_string(),
+            region: region.into(),
+            airac,
+            records: Vec::new(),
+        }
+    }
+
+    /// Set the three letter customer area code.
+    pub fn with_area<S: Into<String>>(mut self, area: S) -> Self {
+        self.area = area.into();
+        self
+    }
+
+    /// The number of records, including continuation records.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Are there no records?
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Push a primary record, and a continuation record holding the name if it is longer than
+    /// the name field of the primary record.
+    fn push_with_name(
+        &mut self,
+        mut record: Record,
+        continuation_col: usize,
+        name_col: usize,
+        name_len: usize,
+        name: &str,
+    ) {
+        record.set(name_col, name_len, name);
+        if name.len() > name_len {
+            let mut continuation = record.clone();
+            for b in &mut continuation.0[continuation_col - 1..] {
+                *b = b' ';
+            }
+            continuation.set(continuation_col, 1, "2");
+            continuation.set(continuation_col + 1, 1, "A");
+            continuation.set(continuation_col + 2, 69, name);
+            record.set(continuation_col, 1, "1");
+            self.records.push(record);
+            self.records.push(continuation);
+        } else {
+            record.set(continuation_col, 1, "0");
+            self.records.push(record);
+        }
+    }
+
+    /// Add navaids as `D` (VHF) and `DB` (NDB) records. Aerodrome navaids, such as ILS
+    /// localisers, belong in the airport section and are left out.
+    pub fn add_navaids(&mut self, navaids: &[NavAid]) -> &mut Self {
+        for navaid in navaids {
+            if navaid.kind.is_aerodrome() {
+                continue;
+            }
+            let name = navaid.name.clone();
+            if navaid.kind == NavAidKind::NDB {
+                let mut record = Record::new(&self.area, 'D', 'B');
+                record.set(14, 4, &navaid.id);
+                record.set(20, 2, &self.region);
+                record.set(23, 5, &format!("{:05}", navaid.frequency_khz * 10));
+                record.set(28, 5, "H");
+                record.set(33, 9, &encode_latitude(navaid.latitude));
+                record.set(42, 10, &encode_longitude(navaid.longitude));
+                record.set(91, 3, "WGE");
+                self.push_with_name(record, 22, 94, 30, &name);
+            } else {
+                let mut record = Record::new(&self.area, 'D', ' ');
+                record.set(14, 4, &navaid.id);
+                record.set(20, 2, &self.region);
+                record.set(23, 5, &format!("{:05}", navaid.frequency_khz / 10));
+                let class = match navaid.kind {
+                    NavAidKind::VOR => "V H",
+                    NavAidKind::VORDME => "VDH",
+                    NavAidKind::DME => " DH",
+                    NavAidKind::TACAN => " TH",
+                    _ => unreachable!(),
+                };
+                record.set(28, 5, class);
+                if navaid.kind != NavAidKind::DME && navaid.kind != NavAidKind::TACAN {
+                    record.set(33, 9, &encode_latitude(navaid.latitude));
+                    record.set(42, 10, &encode_longitude(navaid.longitude));
+                }
+                if navaid.kind != NavAidKind::VOR {
+                    record.set(52, 4, &navaid.id);
+                    record.set(56, 9, &encode_latitude(navaid.latitude));
+                    record.set(65, 10, &encode_longitude(navaid.longitude));
+                    record.set(80, 5, &format!("{:05}", navaid.elevation));
+                }
+                record.set(91, 3, "WGE");
+                self.push_with_name(record, 22, 94, 30, &name);
+            }
+        }
+        self
+    }
+
+    /// Add intersections as `EA` enroute waypoint records.
+    pub fn add_intersections(&mut self, intersections: &[Intersection]) -> &mut Self {
+        for intersection in intersections {
+            let mut record = Record::new(&self.area, 'E', 'A');
+            record.set(7, 4, "ENRT");
+            record.set(14, 5, &intersection.designator);
+            record.set(20, 2, &self.region);
+            record.set(22, 1, "0");
+            record.set(27, 3, "C");
+            record.set(33, 9, &encode_latitude(intersection.latitude));
+            record.set(42, 10, &encode_longitude(intersection.longitude));
+            record.set(85, 3, "WGE");
+            record.set(99, 25, &intersection.designator);
+            self.records.push(record);
+        }
+        self
+    }
+
+    /// Add each waypoint of the airways as `ER` records. `navaids` and `intersections` are used
+    /// to give the section of each waypoint; waypoints that can't be found are assumed to be
+    /// enroute waypoints. Each waypoint is given the level of the segment that starts from it,
+    /// or of the last segment for the final waypoint.
+    pub fn add_airways(
+        &mut self,
+        airways: &[Airway],
+        navaids: &[NavAid],
+        intersections: &[Intersection],
+    ) -> &mut Self {
+        let fixes = Fixes::new(navaids, intersections);
+        for airway in airways {
+            for (i, waypoint) in airway.waypoints.iter().enumerate() {
+                let segment = &airway.waypoints[i.min(airway.waypoints.len().saturating_sub(2))];
+                let level = if is_high_segment(airway, segment, UPPER_AIRSPACE_FL) {
+                    "H"
+                } else {
+                    "L"
+                };
+                let mut record = Record::new(&self.area, 'E', 'R');
+                record.set(14, 5, &airway.designator);
+                record.set(26, 4, &format!("{:04}", (i + 1) * 10));
+                record.set(30, 5, &waypoint.designator);
+                record.set(35, 2, &self.region);
+                let section = match fixes.get(waypoint).and_then(|fix| fix.navaid) {
+                    Some(navaid) if navaid.kind == NavAidKind::NDB => "DB",
+                    Some(_) => "D ",
+                    None => "EA",
+                };
+                record.set(37, 2, section);
+                record.set(39, 1, "0");
+                let description = if i + 1 == airway.waypoints.len() {
+                    "EE"
+                } else {
+                    "E"
+                };
+                record.set(40, 4, description);
+                record.set(45, 1, "O");
+                record.set(46, 1, level);
+                record.set(87, 5, &encode_altitude(&waypoint.lower_limit));
+                record.set(97, 5, &encode_altitude(&waypoint.upper_limit));
+                self.records.push(record);
+            }
+        }
+        self
+    }
+
+    /// Add airports as `PA` airport reference point records.
+    pub fn add_airports(&mut self, airports: &[Airport]) -> &mut Self {
+        for airport in airports {
+            let mut record = Record::new(&self.area, 'P', ' ');
+            record.set(7, 4, &airport.icao);
+            record.set(11, 2, &self.region);
+            record.set(13, 1, "A");
+            record.set(33, 9, &encode_latitude(airport.latitude));
+            record.set(42, 10, &encode_longitude(airport.longitude));
+            record.set(57, 5, &format!("{:05}", airport.elevation));
+            record.set(87, 3, "WGE");
+            self.push_with_name(record, 22, 94, 30, &airport.name);
+        }
+        self
+    }
+}
+
+impl Display for Arinc424 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, record) in self.records.iter().enumerate() {
+            let mut record = record.clone();
+            record.set(124, 5, &format!("{:05}", (i + 1) % 100_000));
+            record.set(129, 4, &self.airac.to_string());
+            writeln!(f, "{}", record)?;
+        }
+        Ok(())
+    }
+}
+
+/// Data decoded from ARINC 424 records written by [`Arinc424`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Decoded {
+    pub(crate) cycle: Option<String>,
+    pub(crate) navaids: Navaids,
+    pub(crate) intersections: Intersections,
+    pub(crate) airways: Airways,
+    pub(crate) airports: Airports,
+}
+
+impl Decoded {
+    /// The cycle of the records, such as `2205`.
+    pub fn cycle(&self) -> Option<&String> {
+        self.cycle.as_ref()
+    }
+
+    /// Navaids from `D` and `DB` records.
+    pub fn navaids(&self) -> &Navaids {
+        &self.navaids
+    }
+
+    /// Intersections from `EA` records.
+    pub fn intersections(&self) -> &Intersections {
+        &self.intersections
+    }
+
+    /// Airways from `ER` records.
+    pub fn airways(&self) -> &Airways {
+        &self.airways
+    }
+
+    /// Airports from `PA` records.
+    pub fn airports(&self) -> &Airports {
+        &self.airports
+    }
+}
+
+/// Decode ARINC 424 records written by [`Arinc424`]. Unsupported record types are ignored.
+///
+/// This is intended for testing: only the fields written by [`Arinc424`] are read, and
+/// coordinates are only as precise as ARINC 424 allows (a hundredth of an arc-second).
+pub fn decode(data: &str) -> Result<Decoded> {
+    let mut decoded = Decoded::default();
+    for line in data.lines().filter(|l| !l.trim().is_empty()) {
+        let record = Record::parse(line)?;
+        if record.get(1, 1) != "S" {
+            continue;
+        }
+        let cycle = record.get(129, 4);
+        if !cycle.is_empty() {
+            decoded.cycle = Some(cycle);
+        }
+
+        let section = (record.get(5, 1), record.get(6, 1), record.get(13, 1));
+        let continuation_col = if section.0 == "E" && section.1 == "R" {
+            39
+        } else {
+            22
+        };
+        let continuation = record.get(continuation_col, 1);
+        if continuation.as_str() > "1" {
+            // A continuation record holds the full name of the previous record.
+            let name = record.get(continuation_col + 2, 69);
+            if section.0 == "P" {
+                if let Some(airport) = decoded.airports.last_mut() {
+                    airport.name = name;
+                }
+            } else if let Some(navaid) = decoded.navaids.last_mut() {
+                navaid.name = name;
+            }
+            continue;
+        }
+
+        match (section.0.as_str(), section.1.as_str(), section.2.as_str()) {
+            ("D", "", _) => {
+                let class = record.get(28, 5);
+                let (has_vor, has_dme, has_tacan) = (
+                    class.starts_with('V'),
+                    class.get(1..2) == Some("D"),
+                    class.get(1..2) == Some("T"),
+                );
+                let kind = match (has_vor, has_dme, has_tacan) {
+                    (true, true, _) => NavAidKind::VORDME,
+                    (true, false, _) => NavAidKind::VOR,
+                    (false, _, true) => NavAidKind::TACAN,
+                    _ => NavAidKind::DME,
+                };
+                let (lat_col, lon_col) = if has_vor { (33, 42) } else { (56, 65) };
+                decoded.navaids.push(NavAid {
+                    id: record.get(14, 4),
+                    name: record.get(94, 30),
+                    kind,
+                    frequency_khz: record.get(23, 5).parse::<usize>().unwrap_or(0) * 10,
+                    latitude: decode_coordinate(&record.get(lat_col, 9))?,
+                    longitude: decode_coordinate(&record.get(lon_col, 10))?,
+                    elevation: record.get(80, 5).parse().unwrap_or(0),
+                    ..Default::default()
+                });
+            }
+            ("D", "B", _) => decoded.navaids.push(NavAid {
+                id: record.get(14, 4),
+                name: record.get(94, 30),
+                kind: NavAidKind::NDB,
+                frequency_khz: record.get(23, 5).parse::<usize>().unwrap_or(0) / 10,
+                latitude: decode_coordinate(&record.get(33, 9))?,
+                longitude: decode_coordinate(&record.get(42, 10))?,
+                ..Default::default()
+            }),
+            ("E", "A", _) => decoded.intersections.push(Intersection {
+                designator: record.get(14, 5),
+                latitude: decode_coordinate(&record.get(33, 9))?,
+                longitude: decode_coordinate(&record.get(42, 10))?,
+            }),
+            ("E", "R", _) => {
+                let designator = record.get(14, 5);
+                if decoded.airways.last().map(|a| &a.designator) != Some(&designator) {
+                    decoded.airways.push(Airway {
+                        designator,
+                        waypoints: Vec::new(),
+                    });
+                }
+                decoded
+                    .airways
+                    .last_mut()
+                    .unwrap()
+                    .waypoints
+                    .push(AirwayWaypoint {
+                        designator: record.get(30, 5),
+                        lower_limit: decode_altitude(&record.get(87, 5)),
+                        upper_limit: decode_altitude(&record.get(97, 5)),
+                    });
+            }
+            ("P", "", "A") => decoded.airports.push(Airport {
+                icao: record.get(7, 4),
+                name: record.get(94, 30),
+                latitude: decode_coordinate(&record.get(33, 9))?,
+                longitude: decode_coordinate(&record.get(42, 10))?,
+                elevation: record.get(57, 5).parse().unwrap_or(0),
+                ..Default::default()
+            }),
+            _ => (),
+        }
+    }
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinates() {
+        assert_eq!("N57185200", encode_latitude(57.31444444));
+        assert_eq!("W002160000", encode_longitude(-2.26666667));
+        assert_eq!("S00000000", encode_latitude(-0.0000001));
+        assert!((decode_coordinate("N57185200").unwrap() - 57.314444).abs() < 1e-6);
+        assert!((decode_coordinate("W002160000").unwrap() + 2.266667).abs() < 1e-6);
+        assert!(decode_coordinate("X1").is_err());
+
+        // Coordinates are parsed from the eAIP into decimal degrees.
+        let (latitude, longitude) = crate::parse::parse_latlong("571839.00N 0021602.00W").unwrap();
+        assert_eq!("N57183900", encode_latitude(latitude.unwrap()));
+        assert_eq!("W002160200", encode_longitude(longitude.unwrap()));
+    }
+
+    #[test]
+    fn test_altitudes() {
+        assert_eq!("FL195", encode_altitude("FL 195"));
+        assert_eq!("05500", encode_altitude("5500 FT ALT"));
+        assert_eq!("UNLTD", encode_altitude("UNL"));
+        assert_eq!("FL 195", decode_altitude("FL195"));
+        assert_eq!("5500 FT ALT", decode_altitude("05500"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let navaids = vec![
+            NavAid {
+                id: "ADN".to_string(),
+                name: "ABERDEEN".to_string(),
+                kind: NavAidKind::VORDME,
+                frequency_khz: 114300,
+                latitude: 57.31,
+                longitude: -2.27,
+                elevation: 600,
+                ..Default::default()
+            },
+            NavAid {
+                id: "ATF".to_string(),
+                name: "ABERDEEN INTERNATIONAL AIRPORT LOCATOR".to_string(),
+                kind: NavAidKind::NDB,
+                frequency_khz: 348,
+                latitude: 57.08,
+                longitude: -2.1,
+                elevation: 0,
+                ..Default::default()
+            },
+        ];
+        let intersections = vec![Intersection {
+            designator: "ABBEW".to_string(),
+            latitude: 50.5,
+            longitude: -1.25,
+        }];
+        let airways = vec![Airway {
+            designator: "UL9".to_string(),
+            waypoints: vec![
+                AirwayWaypoint {
+                    designator: "ADN".to_string(),
+                    lower_limit: "FL 245".to_string(),
+                    upper_limit: "UNL".to_string(),
+                },
+                AirwayWaypoint {
+                    designator: "ABBEW".to_string(),
+                    ..Default::default()
+                },
+            ],
+        }];
+        let airports = vec![Airport {
+            icao: "EGPD".to_string(),
+            name: "ABERDEEN".to_string(),
+            latitude: 57.2,
+            longitude: -2.2,
+            elevation: 215,
+            ..Default::default()
+        }];
+
+        let encode = |navaids: &[NavAid],
+                      intersections: &[Intersection],
+                      airways: &[Airway],
+                      airports: &[Airport]| {
+            let mut encoder = Arinc424::new("EG", AIRAC::from_ymd(2022, 5, 19));
+            encoder
+                .add_navaids(navaids)
+                .add_intersections(intersections)
+                .add_airways(airways, navaids, intersections)
+                .add_airports(airports);
+            encoder
+        };
+
+        let encoder = encode(&navaids, &intersections, &airways, &airports);
+        assert_eq!(7, encoder.len());
+        let encoded = encoder.to_string();
+        for line in encoded.lines() {
+            assert_eq!(RECORD_LENGTH, line.len());
+            assert!(line.ends_with("2205"));
+        }
+        assert!(encoded.starts_with(
+            "SEURD        ADN   EG011430VDH  N57183600W002161200ADN N57183600W002161200"
+        ));
+
+        let levels = encoded
+            .lines()
+            .filter(|line| &line[4..6] == "ER")
+            .map(|line| &line[45..46])
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["H", "H"], levels);
+
+        let decoded = decode(&encoded).unwrap();
+        assert_eq!(Some(&"2205".to_string()), decoded.cycle());
+        assert_eq!(NavAidKind::VORDME, decoded.navaids()[0].kind);
+        assert_eq!(600, decoded.navaids()[0].elevation);
+        assert_eq!(navaids[1].name, decoded.navaids()[1].name);
+        assert_eq!(348, decoded.navaids()[1].frequency_khz);
+        assert!((decoded.intersections()[0].longitude + 1.25).abs() < 1e-6);
+        assert_eq!(airways, decoded.airways);
+        assert_eq!(215, decoded.airports()[0].elevation);
+
+        let reencoded = encode(
+            decoded.navaids(),
+            decoded.intersections(),
+            decoded.airways(),
+            decoded.airports(),
+        );
+        assert_eq!(encoded, reencoded.to_string());
+    }
+}
@@ -0,0 +1,275 @@
+use std::fmt::{Display, Write};
+
+use super::{segments, Fixes};
+use crate::prelude::*;
+
+/// A value of a feature property.
+#[derive(Debug, Clone, PartialEq)]
+enum Property {
+    String(String),
+    Number(f64),
+}
+
+impl Display for Property {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::String(s) => write_json_string(f, s),
+            Self::Number(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+/// The geometry of a feature, in (longitude, latitude) pairs.
+#[derive(Debug, Clone, PartialEq)]
+enum Geometry {
+    Point(f64, f64),
+    LineString(Vec<(f64, f64)>),
+}
+
+/// A GeoJSON feature.
+#[derive(Debug, Clone, PartialEq)]
+struct Feature {
+    geometry: Geometry,
+    properties: Vec<(&'static str, Property)>,
+}
+
+/// A GeoJSON `FeatureCollection` built from eAIP data. This is formatted as GeoJSON with
+/// [`Display`].
+///
+/// Navaids, intersections and airports become `Point` features. Each segment of an airway
+/// becomes a `LineString` feature, with waypoint designators resolved to coordinates using the
+/// given navaids and intersections. Every feature has a `type` property of `navaid`,
+/// `intersection`, `airport` or `airway`, and an `id` property.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FeatureCollection {
+    features: Vec<Feature>,
+}
+
+impl FeatureCollection {
+    /// Create a new, empty, feature collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of features in this collection.
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// Is this collection empty?
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// Add navaids, with `name`, `kind`, `frequency_khz` and `elevation` properties.
+    pub fn add_navaids(&mut self, navaids: &[NavAid]) -> &mut Self {
+        for navaid in navaids {
+            self.features.push(Feature {
+                geometry: Geometry::Point(navaid.longitude, navaid.latitude),
+                properties: vec![
+                    ("type", Property::String("navaid".to_string())),
+                    ("id", Property::String(navaid.id.clone())),
+                    ("name", Property::String(navaid.name.clone())),
+                    ("kind", Property::String(format!("{:?}", navaid.kind))),
+                    (
+                        "frequency_khz",
+                        Property::Number(navaid.frequency_khz as f64),
+                    ),
+                    ("elevation", Property::Number(navaid.elevation as f64)),
+                ],
+            });
+        }
+        self
+    }
+
+    /// Add intersections.
+    pub fn add_intersections(&mut self, intersections: &[Intersection]) -> &mut Self {
+        for intersection in intersections {
+            self.features.push(Feature {
+                geometry: Geometry::Point(intersection.longitude, intersection.latitude),
+                properties: vec![
+                    ("type", Property::String("intersection".to_string())),
+                    ("id", Property::String(intersection.designator.clone())),
+                ],
+            });
+        }
+        self
+    }
+
+    /// Add airports, with `name` and `elevation` properties.
+    pub fn add_airports(&mut self, airports: &[Airport]) -> &mut Self {
+        for airport in airports {
+            self.features.push(Feature {
+                geometry: Geometry::Point(airport.longitude, airport.latitude),
+                properties: vec![
+                    ("type", Property::String("airport".to_string())),
+                    ("id", Property::String(airport.icao.clone())),
+                    ("name", Property::String(airport.name.clone())),
+                    ("elevation", Property::Number(airport.elevation as f64)),
+                ],
+            });
+        }
+        self
+    }
+
+    /// Add each segment of the airways, with `from`, `to`, `lower_limit` and `upper_limit`
+    /// properties. Segments with a waypoint that can't be found in `navaids` or `intersections`
+    /// are left out.
+    pub fn add_airways(
+        &mut self,
+        airways: &[Airway],
+        navaids: &[NavAid],
+        intersections: &[Intersection],
+    ) -> &mut Self {
+        let fixes = Fixes::new(navaids, intersections);
+        for airway in airways {
+            for (from_wp, from, to_wp, to) in segments(airway, &fixes) {
+                self.features.push(Feature {
+                    geometry: Geometry::LineString(vec![
+                        (from.longitude, from.latitude),
+                        (to.longitude, to.latitude),
+                    ]),
+                    properties: vec![
+                        ("type", Property::String("airway".to_string())),
+                        ("id", Property::String(airway.designator.clone())),
+                        ("from", Property::String(from_wp.designator.clone())),
+                        ("to", Property::String(to_wp.designator.clone())),
+                        ("lower_limit", Property::String(from_wp.lower_limit.clone())),
+                        ("upper_limit", Property::String(from_wp.upper_limit.clone())),
+                    ],
+                });
+            }
+        }
+        self
+    }
+}
+
+impl Display for FeatureCollection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, r#"{{"type":"FeatureCollection","features":["#)?;
+        for (i, feature) in self.features.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, r#"{{"type":"Feature","geometry":"#)?;
+            match &feature.geometry {
+                Geometry::Point(lon, lat) => {
+                    write!(f, r#"{{"type":"Point","coordinates":[{},{}]}}"#, lon, lat)?
+                }
+                Geometry::LineString(points) => {
+                    write!(f, r#"{{"type":"LineString","coordinates":["#)?;
+                    for (j, (lon, lat)) in points.iter().enumerate() {
+                        if j > 0 {
+                            write!(f, ",")?;
+                        }
+                        write!(f, "[{},{}]", lon, lat)?;
+                    }
+                    write!(f, "]}}")?;
+                }
+            }
+            write!(f, r#","properties":{{"#)?;
+            for (j, (key, value)) in feature.properties.iter().enumerate() {
+                if j > 0 {
+                    write!(f, ",")?;
+                }
+                write!(f, r#""{}":{}"#, key, value)?;
+            }
+            write!(f, "}}}}")?;
+        }
+        write!(f, "]}}")
+    }
+}
+
+/// Write a string as a JSON string literal.
+fn write_json_string<W: Write>(w: &mut W, s: &str) -> std::fmt::Result {
+    w.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => w.write_str("\\\"")?,
+            '\\' => w.write_str("\\\\")?,
+            '\n' => w.write_str("\\n")?,
+            '\r' => w.write_str("\\r")?,
+            '\t' => w.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(w, "\\u{:04x}", c as u32)?,
+            c => w.write_char(c)?,
+        }
+    }
+    w.write_char('"')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_collection() {
+        let navaids = vec![NavAid {
+            id: "ADN".to_string(),
+            name: "ABERDEEN \"DYCE\"".to_string(),
+            kind: NavAidKind::VORDME,
+            frequency_khz: 114300,
+            latitude: 57.31,
+            longitude: -2.27,
+            elevation: 600,
+            ..Default::default()
+        }];
+        let intersections = vec![Intersection {
+            designator: "ABBEW".to_string(),
+            latitude: 57.5,
+            longitude: -2.0,
+        }];
+        let airways = vec![Airway {
+            designator: "L9".to_string(),
+            waypoints: vec![
+                AirwayWaypoint {
+                    designator: "ADN".to_string(),
+                    lower_limit: "FL 75".to_string(),
+                    upper_limit: "FL 195".to_string(),
+                },
+                AirwayWaypoint {
+                    designator: "ABBEW".to_string(),
+                    ..Default::default()
+                },
+                AirwayWaypoint {
+                    designator: "ZZZZZ".to_string(),
+                    ..Default::default()
+                },
+            ],
+        }];
+
+        let mut collection = FeatureCollection::new();
+        collection
+            .add_navaids(&navaids)
+            .add_intersections(&intersections)
+            .add_airways(&airways, &navaids, &intersections);
+        assert_eq!(3, collection.len());
+
+        let json: serde_json::Value = serde_json::from_str(&collection.to_string()).unwrap();
+        let features = json["features"].as_array().unwrap();
+        assert_eq!("ABERDEEN \"DYCE\"", features[0]["properties"]["name"]);
+        assert_eq!(114300.0, features[0]["properties"]["frequency_khz"]);
+        assert_eq!(-2.27, features[0]["geometry"]["coordinates"][0]);
+        assert_eq!("LineString", features[2]["geometry"]["type"]);
+        assert_eq!("FL 195", features[2]["properties"]["upper_limit"]);
+        assert_eq!(57.5, features[2]["geometry"]["coordinates"][1][1]);
+    }
+
+    #[test]
+    fn test_parsed_coordinates() {
+        let navaids = Navaids::parse(
+            r#"<html><body><table><tbody><tr>
+            <td><strong>ABERDEEN</strong><p class="line">VOR/DME</p></td>
+            <td>ADN</td><td>114.300 MHz</td><td>H24</td>
+            <td>571839.00N 0021602.00W</td><td>600 FT</td>
+            </tr></tbody></table></body></html>"#,
+        )
+        .unwrap();
+        let mut collection = FeatureCollection::new();
+        collection.add_navaids(&navaids);
+
+        let json: serde_json::Value = serde_json::from_str(&collection.to_string()).unwrap();
+        let coordinates = &json["features"][0]["geometry"]["coordinates"];
+        assert!((coordinates[0].as_f64().unwrap() + 2.267222).abs() < 1e-6);
+        assert!((coordinates[1].as_f64().unwrap() - 57.310833).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use self::xplane::flight_level;
+use crate::prelude::*;
+
+/// Export to AIXM 5.1.
+pub mod aixm;
+
+/// Export to ARINC 424 records.
+pub mod arinc424;
+
+/// Export to GeoJSON.
+pub mod geojson;
+
+/// Export to EuroScope and VRC sector files.
+pub mod sct;
+
+/// Export to a SQLite navigation database.
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+/// Export to X-Plane navigation data files.
+pub mod xplane;
+
+/// The position and type of a waypoint that an airway can refer to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Fix<'a> {
+    pub(crate) latitude: f64,
+    pub(crate) longitude: f64,
+    pub(crate) navaid: Option<&'a NavAid>,
+}
+
+/// A lookup of airway waypoint designators to navaids and intersections.
+#[derive(Debug, Default)]
+pub(crate) struct Fixes<'a> {
+    fixes: HashMap<&'a str, Fix<'a>>,
+}
+
+impl<'a> Fixes<'a> {
+    /// Create a lookup from lists of navaids and intersections. If more than one navaid has the
+    /// same identifier, the first is used.
+    pub(crate) fn new(navaids: &'a [NavAid], intersections: &'a [Intersection]) -> Self {
+        let mut fixes = HashMap::new();
+        for navaid in navaids {
+            fixes.entry(navaid.id.as_str()).or_insert(Fix {
+                latitude: navaid.latitude,
+                longitude: navaid.longitude,
+                navaid: Some(navaid),
+            });
+        }
+        for intersection in intersections {
+            fixes
+                .entry(intersection.designator.as_str())
+                .or_insert(Fix {
+                    latitude: intersection.latitude,
+                    longitude: intersection.longitude,
+                    navaid: None,
+                });
+        }
+        Self { fixes }
+    }
+
+    /// Find the fix for an airway waypoint.
+    pub(crate) fn get(&self, waypoint: &AirwayWaypoint) -> Option<Fix<'a>> {
+        let fix = self.fixes.get(waypoint.designator.as_str()).copied();
+        if fix.is_none() {
+            log::warn!("Cannot resolve airway waypoint: {}", waypoint.designator);
+        }
+        fix
+    }
+}
+
+/// Each segment of an airway with both ends resolved, along with the waypoint the segment
+/// starts from, which holds the limits of the segment.
+pub(crate) fn segments<'a>(
+    airway: &'a Airway,
+    fixes: &Fixes<'a>,
+) -> Vec<(&'a AirwayWaypoint, Fix<'a>, &'a AirwayWaypoint, Fix<'a>)> {
+    airway
+        .waypoints
+        .windows(2)
+        .filter_map(|pair| {
+            let from = fixes.get(&pair[0])?;
+            let to = fixes.get(&pair[1])?;
+            Some((&pair[0], from, &pair[1], to))
+        })
+        .collect()
+}
+
+/// Is the segment of an airway starting at `waypoint` in the upper airspace, from
+/// `upper_airspace_fl`? Segments are classified by their limits: those with a lower limit from
+/// `upper_airspace_fl` are high, and those with a lower limit below it or, without a lower limit,
+/// an upper limit up to it, are low. Only if the limits don't decide are airways with a
+/// designator starting with `U` high.
+pub(crate) fn is_high_segment(
+    airway: &Airway,
+    waypoint: &AirwayWaypoint,
+    upper_airspace_fl: usize,
+) -> bool {
+    let lower = flight_level(&waypoint.lower_limit);
+    let upper = flight_level(&waypoint.upper_limit);
+    match (lower, upper) {
+        (Some(lower), _) => lower >= upper_airspace_fl,
+        (None, Some(upper)) if upper <= upper_airspace_fl => false,
+        _ => airway.designator.starts_with('U'),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sct::UPPER_AIRSPACE_FL;
+    use super::*;
+
+    #[test]
+    fn test_is_high_segment() {
+        let airway = |designator: &str, lower: &str, upper: &str| Airway {
+            designator: designator.to_string(),
+            waypoints: vec![AirwayWaypoint {
+                designator: "ADN".to_string(),
+                lower_limit: lower.to_string(),
+                upper_limit: upper.to_string(),
+            }],
+        };
+        let is_high =
+            |airway: Airway| is_high_segment(&airway, &airway.waypoints[0], UPPER_AIRSPACE_FL);
+        assert!(is_high(airway("L9", "FL 255", "FL 460")));
+        assert!(!is_high(airway("UL10", "FL 75", "FL 195")));
+        assert!(!is_high(airway("UL10", "", "FL 195")));
+        assert!(is_high(airway("UL11", "", "")));
+        assert!(is_high(airway("UL12", "", "UNL")));
+        assert!(!is_high(airway("L12", "", "UNL")));
+        assert!(!is_high(airway("L13", "", "")));
+    }
+}
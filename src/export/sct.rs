@@ -0,0 +1,352 @@
+use std::fmt::{Display, Write};
+
+use super::{is_high_segment, segments, Fixes};
+use crate::prelude::*;
+
+/// The flight level at which upper airspace starts, by default.
+pub const UPPER_AIRSPACE_FL: usize = 245;
+
+/// Format a latitude as `N057.18.36.000`.
+fn latitude(latitude: f64) -> String {
+    let hemisphere = if latitude < 0f64 { 'S' } else { 'N' };
+    format!("{}{}", hemisphere, dms(latitude.abs()))
+}
+
+/// Format a longitude as `W002.16.12.000`.
+fn longitude(longitude: f64) -> String {
+    let hemisphere = if longitude < 0f64 { 'W' } else { 'E' };
+    format!("{}{}", hemisphere, dms(longitude.abs()))
+}
+
+fn dms(value: f64) -> String {
+    let millis = (value * 3_600_000f64).round() as u64;
+    format!(
+        "{:03}.{:02}.{:02}.{:03}",
+        millis / 3_600_000,
+        millis % 3_600_000 / 60_000,
+        millis % 60_000 / 1_000,
+        millis % 1_000
+    )
+}
+
+/// The sections of a EuroScope or VRC sector file (`.sct`) built from eAIP data. This is
+/// formatted as the `[VOR]`, `[NDB]`, `[FIXES]`, `[AIRPORT]`, `[HIGH AIRWAY]` and `[LOW AIRWAY]`
+/// sections with [`Display`].
+///
+/// VORs, DMEs and TACANs are written to `[VOR]` and NDBs to `[NDB]`. Airports are written with
+/// their first tower frequency, or their first information frequency if they have no tower. If an
+/// airport has neither, or its communications haven't been fetched, the frequency is `000.000`.
+/// Airspace classes aren't parsed from the eAIP, so every airport is written with class `D`.
+///
+/// Airway segments with a lower limit in the upper airspace are high airways and all others are
+/// low airways, as in the other exporters. Only if the limits are missing are airways with a
+/// designator starting with `U` high airways.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectorFile {
+    upper_airspace_fl: usize,
+    vors: Vec<String>,
+    ndbs: Vec<String>,
+    fixes: Vec<String>,
+    airports: Vec<String>,
+    high_airways: Vec<String>,
+    low_airways: Vec<String>,
+}
+
+impl Default for SectorFile {
+    fn default() -> Self {
+        Self {
+            upper_airspace_fl: UPPER_AIRSPACE_FL,
+            vors: Vec::new(),
+            ndbs: Vec::new(),
+            fixes: Vec::new(),
+            airports: Vec::new(),
+            high_airways: Vec::new(),
+            low_airways: Vec::new(),
+        }
+    }
+}
+
+impl SectorFile {
+    /// Create a new, empty, sector file.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the flight level at which upper airspace starts, used to classify airway segments.
+    /// Defaults to [`UPPER_AIRSPACE_FL`].
+    pub fn with_upper_airspace(mut self, flight_level: usize) -> Self {
+        self.upper_airspace_fl = flight_level;
+        self
+    }
+
+    /// Is this sector file empty?
+    pub fn is_empty(&self) -> bool {
+        self.vors.is_empty()
+            && self.ndbs.is_empty()
+            && self.fixes.is_empty()
+            && self.airports.is_empty()
+            && self.high_airways.is_empty()
+            && self.low_airways.is_empty()
+    }
+
+    /// Add navaids to the `[VOR]` and `[NDB]` sections. Locators are added as NDBs, and other
+    /// aerodrome navaids, such as ILS localisers, are left out.
+    pub fn add_navaids(&mut self, navaids: &[NavAid]) -> &mut Self {
+        for navaid in navaids {
+            let position = format!(
+                "{} {}",
+                latitude(navaid.latitude),
+                longitude(navaid.longitude)
+            );
+            match navaid.kind {
+                NavAidKind::NDB | NavAidKind::Locator => self.ndbs.push(format!(
+                    "{:<4} {:07.3} {}",
+                    navaid.id, navaid.frequency_khz as f64, position
+                )),
+                kind if kind.is_aerodrome() => (),
+                _ => self.vors.push(format!(
+                    "{:<4} {:07.3} {}",
+                    navaid.id,
+                    navaid.frequency_khz as f64 / 1000f64,
+                    position
+                )),
+            }
+        }
+        self
+    }
+
+    /// Add intersections to the `[FIXES]` section.
+    pub fn add_intersections(&mut self, intersections: &[Intersection]) -> &mut Self {
+        for intersection in intersections {
+            self.fixes.push(format!(
+                "{:<5} {} {}",
+                intersection.designator,
+                latitude(intersection.latitude),
+                longitude(intersection.longitude)
+            ));
+        }
+        self
+    }
+
+    /// Add airports to the `[AIRPORT]` section.
+    pub fn add_airports(&mut self, airports: &[Airport]) -> &mut Self {
+        for airport in airports {
+            let frequency_khz = [
+                CommunicationServiceKind::Tower,
+                CommunicationServiceKind::Information,
+            ]
+            .into_iter()
+            .find_map(|kind| {
+                airport
+                    .communications_of(kind)
+                    .into_iter()
+                    .find_map(|c| c.frequencies_khz.first().copied())
+            })
+            .unwrap_or(0);
+            self.airports.push(format!(
+                "{} {:03}.{:03} {} {} D",
+                airport.icao,
+                frequency_khz / 1000,
+                frequency_khz % 1000,
+                latitude(airport.latitude),
+                longitude(airport.longitude)
+            ));
+        }
+        self
+    }
+
+    /// Add each segment of the airways to the `[HIGH AIRWAY]` or `[LOW AIRWAY]` section.
+    /// Segments with a waypoint that can't be found in `navaids` or `intersections` are left
+    /// out.
+    pub fn add_airways(
+        &mut self,
+        airways: &[Airway],
+        navaids: &[NavAid],
+        intersections: &[Intersection],
+    ) -> &mut Self {
+        let fixes = Fixes::new(navaids, intersections);
+        for airway in airways {
+            for (from_wp, from, _, to) in segments(airway, &fixes) {
+                let line = format!(
+                    "{:<6} {} {} {} {}",
+                    airway.designator,
+                    latitude(from.latitude),
+                    longitude(from.longitude),
+                    latitude(to.latitude),
+                    longitude(to.longitude)
+                );
+                if is_high_segment(airway, from_wp, self.upper_airspace_fl) {
+                    self.high_airways.push(line);
+                } else {
+                    self.low_airways.push(line);
+                }
+            }
+        }
+        self
+    }
+}
+
+impl Display for SectorFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "; Generated by eaip-rs from eAIP data, not for real world navigation."
+        )?;
+        for (name, lines) in [
+            ("VOR", &self.vors),
+            ("NDB", &self.ndbs),
+            ("FIXES", &self.fixes),
+            ("AIRPORT", &self.airports),
+            ("HIGH AIRWAY", &self.high_airways),
+            ("LOW AIRWAY", &self.low_airways),
+        ] {
+            writeln!(f, "\n[{}]", name)?;
+            for line in lines {
+                f.write_str(line)?;
+                f.write_char('\n')?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dms() {
+        assert_eq!("N057.18.36.000", latitude(57.31));
+        assert_eq!("W002.16.12.000", longitude(-2.27));
+        assert_eq!("S033.59.59.999", latitude(-33.99999972));
+        assert_eq!("E000.00.00.000", longitude(0f64));
+    }
+
+    #[test]
+    fn test_sector_file() {
+        let navaids = vec![
+            NavAid {
+                id: "ADN".to_string(),
+                kind: NavAidKind::VORDME,
+                frequency_khz: 114300,
+                latitude: 57.31,
+                longitude: -2.27,
+                ..Default::default()
+            },
+            NavAid {
+                id: "ATF".to_string(),
+                kind: NavAidKind::NDB,
+                frequency_khz: 348,
+                latitude: 57.08,
+                longitude: -2.1,
+                ..Default::default()
+            },
+        ];
+        let intersections = vec![Intersection {
+            designator: "ABBEW".to_string(),
+            latitude: 57.5,
+            longitude: -2.0,
+        }];
+        let airway = |designator: &str, lower: &str, upper: &str| Airway {
+            designator: designator.to_string(),
+            waypoints: vec![
+                AirwayWaypoint {
+                    designator: "ADN".to_string(),
+                    lower_limit: lower.to_string(),
+                    upper_limit: upper.to_string(),
+                },
+                AirwayWaypoint {
+                    designator: "ABBEW".to_string(),
+                    ..Default::default()
+                },
+            ],
+        };
+        let airways = vec![
+            airway("UL9", "FL 75", "UNL"),
+            airway("L7", "FL 75", "FL 195"),
+            airway("N8", "FL 75", "FL 460"),
+            airway("Y9", "FL 255", "FL 460"),
+            airway("UL10", "FL 75", "FL 195"),
+            airway("UL11", "", ""),
+        ];
+
+        let airports = vec![
+            Airport {
+                icao: "EGPD".to_string(),
+                latitude: 57.25,
+                longitude: -2.25,
+                communications: vec![
+                    CommunicationService {
+                        kind: CommunicationServiceKind::Approach,
+                        frequencies_khz: vec![119055],
+                        ..Default::default()
+                    },
+                    CommunicationService {
+                        kind: CommunicationServiceKind::Tower,
+                        frequencies_khz: vec![118105, 123300],
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+            Airport {
+                icao: "EGPY".to_string(),
+                latitude: 56.5,
+                longitude: -3.0,
+                ..Default::default()
+            },
+        ];
+
+        let mut sct = SectorFile::new();
+        assert!(sct.is_empty());
+        sct.add_navaids(&navaids)
+            .add_intersections(&intersections)
+            .add_airports(&airports)
+            .add_airways(&airways, &navaids, &intersections);
+        let out = sct.to_string();
+
+        assert!(out.contains("\n[VOR]\nADN  114.300 N057.18.36.000 W002.16.12.000\n"));
+        assert!(out.contains("\n[NDB]\nATF  348.000 N057.04.48.000 W002.06.00.000\n"));
+        assert!(out.contains("\n[FIXES]\nABBEW N057.30.00.000 W002.00.00.000\n"));
+        assert!(out.contains(
+            "\n[AIRPORT]\nEGPD 118.105 N057.15.00.000 W002.15.00.000 D\nEGPY 000.000 N056.30.00.000 W003.00.00.000 D\n"
+        ));
+        let high = out
+            .split("[HIGH AIRWAY]\n")
+            .nth(1)
+            .unwrap()
+            .split("\n[")
+            .next()
+            .unwrap();
+        let low = out.split("[LOW AIRWAY]\n").nth(1).unwrap();
+        let designators = |section: &str| {
+            section
+                .lines()
+                .filter_map(|l| l.split_whitespace().next())
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec!["Y9", "UL11"], designators(high));
+        assert_eq!(vec!["UL9", "L7", "N8", "UL10"], designators(low));
+        assert!(
+            low.starts_with("UL9    N057.18.36.000 W002.16.12.000 N057.30.00.000 W002.00.00.000")
+        );
+    }
+
+    #[test]
+    fn test_parsed_coordinates() {
+        let navaids = Navaids::parse(
+            r#"<html><body><table><tbody><tr>
+            <td><strong>ABERDEEN</strong><p class="line">VOR/DME</p></td>
+            <td>ADN</td><td>114.300 MHz</td><td>H24</td>
+            <td>571839.00N 0021602.00W</td><td>600 FT</td>
+            </tr></tbody></table></body></html>"#,
+        )
+        .unwrap();
+        let mut sct = SectorFile::new();
+        sct.add_navaids(&navaids);
+        assert!(sct
+            .to_string()
+            .contains("\n[VOR]\nADN  114.300 N057.18.39.000 W002.16.02.000\n"));
+    }
+}
@@ -0,0 +1,376 @@
+use std::path::Path;
+
+use airac::AIRAC;
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::prelude::*;
+
+/// The schema of the navigation database. Every table is keyed back to a row of `cycles`,
+/// which records the AIS and AIRAC the data came from.
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS cycles (
+    id INTEGER PRIMARY KEY,
+    ais TEXT NOT NULL,
+    airac TEXT NOT NULL,
+    effective TEXT NOT NULL,
+    UNIQUE (ais, airac)
+);
+
+CREATE TABLE IF NOT EXISTS navaids (
+    id INTEGER PRIMARY KEY,
+    cycle_id INTEGER NOT NULL REFERENCES cycles (id) ON DELETE CASCADE,
+    ident TEXT NOT NULL,
+    name TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    frequency_khz INTEGER NOT NULL,
+    latitude REAL NOT NULL,
+    longitude REAL NOT NULL,
+    elevation INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS navaids_ident ON navaids (cycle_id, ident);
+
+CREATE TABLE IF NOT EXISTS intersections (
+    id INTEGER PRIMARY KEY,
+    cycle_id INTEGER NOT NULL REFERENCES cycles (id) ON DELETE CASCADE,
+    designator TEXT NOT NULL,
+    latitude REAL NOT NULL,
+    longitude REAL NOT NULL
+);
+CREATE INDEX IF NOT EXISTS intersections_designator ON intersections (cycle_id, designator);
+
+CREATE TABLE IF NOT EXISTS airways (
+    id INTEGER PRIMARY KEY,
+    cycle_id INTEGER NOT NULL REFERENCES cycles (id) ON DELETE CASCADE,
+    designator TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS airways_designator ON airways (cycle_id, designator);
+
+CREATE TABLE IF NOT EXISTS airway_segments (
+    id INTEGER PRIMARY KEY,
+    airway_id INTEGER NOT NULL REFERENCES airways (id) ON DELETE CASCADE,
+    sequence INTEGER NOT NULL,
+    from_designator TEXT NOT NULL,
+    to_designator TEXT NOT NULL,
+    lower_limit TEXT NOT NULL,
+    upper_limit TEXT NOT NULL,
+    UNIQUE (airway_id, sequence)
+);
+CREATE INDEX IF NOT EXISTS airway_segments_from ON airway_segments (from_designator);
+CREATE INDEX IF NOT EXISTS airway_segments_to ON airway_segments (to_designator);
+
+CREATE TABLE IF NOT EXISTS airports (
+    id INTEGER PRIMARY KEY,
+    cycle_id INTEGER NOT NULL REFERENCES cycles (id) ON DELETE CASCADE,
+    icao TEXT NOT NULL,
+    name TEXT NOT NULL,
+    latitude REAL NOT NULL,
+    longitude REAL NOT NULL,
+    elevation INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS airports_icao ON airports (cycle_id, icao);
+
+CREATE TABLE IF NOT EXISTS charts (
+    id INTEGER PRIMARY KEY,
+    airport_id INTEGER NOT NULL REFERENCES airports (id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    url TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS charts_airport ON charts (airport_id);
+";
+
+/// A SQLite navigation database, holding the data of any number of AIRAC cycles from any
+/// number of AISs.
+///
+/// Each cycle is a row of the `cycles` table, identified by the AIS (such as the country code
+/// from [`NamedEAIP::country`](crate::eaip::ais::NamedEAIP::country)) and AIRAC. The `navaids`,
+/// `intersections`, `airways` and `airports` tables refer to a cycle with `cycle_id`. Each
+/// airway has a row in `airway_segments` for each segment, numbered in order by `sequence`
+/// starting at 1, and each airport a row in `charts` for each chart.
+#[derive(Debug)]
+pub struct NavDatabase {
+    conn: Connection,
+}
+
+impl NavDatabase {
+    /// Open, or create, a navigation database file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    /// Create a navigation database in memory.
+    pub fn open_in_memory() -> Result<Self> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    fn from_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch("PRAGMA foreign_keys = ON;")?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// The underlying connection, to query the database.
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// The AIS and AIRAC of each cycle in the database.
+    pub fn cycles(&self) -> Result<Vec<(String, String)>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT ais, airac FROM cycles ORDER BY ais, effective")?;
+        let cycles = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(cycles)
+    }
+
+    /// Remove a cycle and all of its data. Returns false if the cycle wasn't in the database.
+    pub fn remove_cycle(&mut self, ais: &str, airac: &AIRAC) -> Result<bool> {
+        let removed = self.conn.execute(
+            "DELETE FROM cycles WHERE ais = ?1 AND airac = ?2",
+            params![ais, airac.to_string()],
+        )?;
+        Ok(removed > 0)
+    }
+
+    /// Write the data of a cycle, replacing any data already held for the same AIS and AIRAC.
+    /// This is done in a single transaction. Returns the `id` of the cycle.
+    ///
+    /// Airports are written as they are in the snapshot, so should be fetched individually
+    /// first if their positions and charts are wanted, as [`import_from_eaip`](Self::import_from_eaip)
+    /// does.
+    pub fn import(&mut self, ais: &str, airac: &AIRAC, snapshot: &Snapshot) -> Result<i64> {
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            "DELETE FROM cycles WHERE ais = ?1 AND airac = ?2",
+            params![ais, airac.to_string()],
+        )?;
+        tx.execute(
+            "INSERT INTO cycles (ais, airac, effective) VALUES (?1, ?2, ?3)",
+            params![ais, airac.to_string(), airac.starts().to_string()],
+        )?;
+        let cycle_id = tx.last_insert_rowid();
+
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO navaids (cycle_id, ident, name, kind, frequency_khz, latitude, longitude, elevation)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            )?;
+            for navaid in snapshot.navaids() {
+                stmt.execute(params![
+                    cycle_id,
+                    navaid.id,
+                    navaid.name,
+                    format!("{:?}", navaid.kind),
+                    navaid.frequency_khz as i64,
+                    navaid.latitude,
+                    navaid.longitude,
+                    navaid.elevation as i64,
+                ])?;
+            }
+
+            let mut stmt = tx.prepare(
+                "INSERT INTO intersections (cycle_id, designator, latitude, longitude)
+                VALUES (?1, ?2, ?3, ?4)",
+            )?;
+            for intersection in snapshot.intersections() {
+                stmt.execute(params![
+                    cycle_id,
+                    intersection.designator,
+                    intersection.latitude,
+                    intersection.longitude,
+                ])?;
+            }
+
+            let mut airway_stmt =
+                tx.prepare("INSERT INTO airways (cycle_id, designator) VALUES (?1, ?2)")?;
+            let mut segment_stmt = tx.prepare(
+                "INSERT INTO airway_segments (airway_id, sequence, from_designator, to_designator, lower_limit, upper_limit)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            )?;
+            for airway in snapshot.airways() {
+                let airway_id = airway_stmt.insert(params![cycle_id, airway.designator])?;
+                for (i, pair) in airway.waypoints.windows(2).enumerate() {
+                    segment_stmt.execute(params![
+                        airway_id,
+                        i as i64 + 1,
+                        pair[0].designator,
+                        pair[1].designator,
+                        pair[0].lower_limit,
+                        pair[0].upper_limit,
+                    ])?;
+                }
+            }
+
+            let mut airport_stmt = tx.prepare(
+                "INSERT INTO airports (cycle_id, icao, name, latitude, longitude, elevation)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            )?;
+            let mut chart_stmt =
+                tx.prepare("INSERT INTO charts (airport_id, title, url) VALUES (?1, ?2, ?3)")?;
+            for airport in snapshot.airports() {
+                let airport_id = airport_stmt.insert(params![
+                    cycle_id,
+                    airport.icao,
+                    airport.name,
+                    airport.latitude,
+                    airport.longitude,
+                    airport.elevation as i64,
+                ])?;
+                for chart in &airport.charts {
+                    chart_stmt.execute(params![airport_id, chart.title, chart.url])?;
+                }
+            }
+        }
+
+        tx.commit()?;
+        Ok(cycle_id)
+    }
+
+    /// Fetch a full cycle from an eAIP, including the details and charts of every airport, and
+    /// write it with [`import`](Self::import). Airports whose details can't be fetched are
+    /// written with just their ICAO code and name.
+    pub async fn import_from_eaip(&mut self, ais: &str, eaip: &EAIP, airac: AIRAC) -> Result<i64> {
+        let mut snapshot = Snapshot::from_eaip(eaip, airac.clone()).await?;
+        let mut airports = Vec::with_capacity(snapshot.airports.len());
+        for airport in snapshot.airports {
+            match Airport::from_eaip(eaip, airac.clone(), airport.icao.clone()).await {
+                Ok(details) => airports.push(details),
+                Err(e) => {
+                    log::warn!("Failed to get details of airport {}: {}", airport.icao, e);
+                    airports.push(airport);
+                }
+            }
+        }
+        snapshot.airports = airports;
+        self.import(ais, &airac, &snapshot)
+    }
+
+    /// The `id` of a cycle, if it is in the database.
+    pub fn cycle_id(&self, ais: &str, airac: &AIRAC) -> Result<Option<i64>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT id FROM cycles WHERE ais = ?1 AND airac = ?2",
+                params![ais, airac.to_string()],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(name: &str) -> Snapshot {
+        Snapshot::new(
+            vec![NavAid {
+                id: "ADN".to_string(),
+                name: name.to_string(),
+                kind: NavAidKind::VORDME,
+                frequency_khz: 114300,
+                latitude: 57.31,
+                longitude: -2.27,
+                elevation: 600,
+                ..Default::default()
+            }],
+            vec![Intersection {
+                designator: "ABBEW".to_string(),
+                latitude: 57.5,
+                longitude: -2.0,
+            }],
+            vec![Airway {
+                designator: "L9".to_string(),
+                waypoints: vec![
+                    AirwayWaypoint {
+                        designator: "ADN".to_string(),
+                        lower_limit: "FL 75".to_string(),
+                        upper_limit: "FL 195".to_string(),
+                    },
+                    AirwayWaypoint {
+                        designator: "ABBEW".to_string(),
+                        lower_limit: "FL 95".to_string(),
+                        upper_limit: "FL 195".to_string(),
+                    },
+                    AirwayWaypoint {
+                        designator: "ATF".to_string(),
+                        ..Default::default()
+                    },
+                ],
+            }],
+            vec![Airport {
+                icao: "EGPD".to_string(),
+                name: "ABERDEEN".to_string(),
+                charts: vec![Chart {
+                    title: "Aerodrome Chart".to_string(),
+                    url: "https://example.com/EGPD.pdf".to_string(),
+                }],
+                ..Default::default()
+            }],
+        )
+    }
+
+    #[test]
+    fn test_import() {
+        let may = AIRAC::from_ymd(2022, 5, 19);
+        let june = may.next();
+        let mut db = NavDatabase::open_in_memory().unwrap();
+        let may_id = db.import("GB", &may, &snapshot("ABERDEEN")).unwrap();
+        db.import("GB", &june, &snapshot("ABERDEEN")).unwrap();
+        db.import("NL", &june, &snapshot("ABERDEEN")).unwrap();
+        // Importing a cycle again replaces it.
+        let may_id = {
+            let id = db.import("GB", &may, &snapshot("DYCE")).unwrap();
+            assert_ne!(may_id, id);
+            id
+        };
+        assert_eq!(Some(may_id), db.cycle_id("GB", &may).unwrap());
+        assert_eq!(
+            vec![
+                ("GB".to_string(), "2205".to_string()),
+                ("GB".to_string(), "2206".to_string()),
+                ("NL".to_string(), "2206".to_string()),
+            ],
+            db.cycles().unwrap()
+        );
+
+        assert_eq!(3, count(&db, "navaids"));
+        assert_eq!(6, count(&db, "airway_segments"));
+        assert_eq!(3, count(&db, "charts"));
+
+        let conn = db.connection();
+        let name: String = conn
+            .query_row(
+                "SELECT name FROM navaids WHERE cycle_id = ?1 AND ident = 'ADN'",
+                [may_id],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!("DYCE", name);
+        let segment: (String, String, String) = conn
+            .query_row(
+                "SELECT s.from_designator, s.to_designator, s.lower_limit FROM airway_segments s
+                JOIN airways a ON a.id = s.airway_id
+                WHERE a.cycle_id = ?1 AND a.designator = 'L9' AND s.sequence = 2",
+                [may_id],
+                |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(
+            ("ABBEW".to_string(), "ATF".to_string(), "FL 95".to_string()),
+            segment
+        );
+
+        assert!(db.remove_cycle("NL", &june).unwrap());
+        assert!(!db.remove_cycle("NL", &june).unwrap());
+        assert_eq!(2, count(&db, "airports"));
+        assert_eq!(2, count(&db, "charts"));
+    }
+
+    fn count(db: &NavDatabase, table: &str) -> i64 {
+        db.connection()
+            .query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |r| r.get(0))
+            .unwrap()
+    }
+}
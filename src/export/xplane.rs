@@ -0,0 +1,312 @@
+use std::fmt::Write;
+
+use airac::AIRAC;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::sct::UPPER_AIRSPACE_FL;
+use super::{is_high_segment, segments, Fixes};
+use crate::prelude::*;
+
+/// The row type of a waypoint in `earth_awy.dat`.
+const AWY_FIX: u8 = 11;
+const AWY_NDB: u8 = 2;
+const AWY_VHF: u8 = 3;
+
+/// The header of an X-Plane data file.
+fn header(version: u16, metadata: &str, airac: &AIRAC) -> String {
+    format!(
+        "I\n{} Version - data cycle {}, build {}, metadata {}. Generated by eaip-rs from eAIP data, not for real world navigation.\n\n",
+        version,
+        airac,
+        airac.starts().format("%Y%m%d"),
+        metadata,
+    )
+}
+
+/// Produce `earth_fix.dat` (version 1101) from intersections. `region` is the ICAO region the
+/// intersections are in, such as `EG`.
+pub fn earth_fix(intersections: &[Intersection], region: &str, airac: &AIRAC) -> String {
+    // Waypoint type from ARINC 424 columns 27 to 29, one byte per column: a combined named
+    // intersection and RNAV waypoint.
+    let waypoint_type = u32::from(b'C') | u32::from(b' ') << 8 | u32::from(b' ') << 16;
+
+    let mut out = header(1101, "FixXP1101", airac);
+    for intersection in intersections {
+        writeln!(
+            out,
+            "{:13.9} {:14.9} {} ENRT {} {}",
+            intersection.latitude,
+            intersection.longitude,
+            intersection.designator,
+            region,
+            waypoint_type
+        )
+        .unwrap();
+    }
+    out += "99\n";
+    out
+}
+
+/// Produce `earth_nav.dat` (version 1150) from navaids. `region` is the ICAO region the navaids
+/// are in, such as `EG`.
+///
+/// VORs are written as type 3 rows and NDBs as type 2 rows. DMEs are written as type 13 rows,
+/// apart from the DME of a VOR/DME, which is written as a type 12 row alongside the VOR. TACANs
+/// are written as type 13 rows, using their paired VHF frequency. Magnetic variation isn't
+/// given in the eAIP data, so is written as zero. Aerodrome navaids, such as ILS localisers,
+/// are left out, as X-Plane needs the airport they serve.
+pub fn earth_nav(navaids: &[NavAid], region: &str, airac: &AIRAC) -> String {
+    let mut out = header(1150, "NavXP1150", airac);
+    for navaid in navaids {
+        let position = format!(
+            "{:13.9} {:14.9} {:6}",
+            navaid.latitude, navaid.longitude, navaid.elevation
+        );
+        let vhf = navaid.frequency_khz / 10;
+        let name = format!("{} {}", navaid.name, kind_name(navaid.kind));
+        let ident = format!("{} ENRT {} {}", navaid.id, region, name);
+        match navaid.kind {
+            NavAidKind::VOR => {
+                writeln!(out, " 3 {} {:05} 130      0.000 {}", position, vhf, ident).unwrap()
+            }
+            NavAidKind::VORDME => {
+                writeln!(out, " 3 {} {:05} 130      0.000 {}", position, vhf, ident).unwrap();
+                writeln!(out, "12 {} {:05} 130      0.000 {}", position, vhf, ident).unwrap();
+            }
+            NavAidKind::DME | NavAidKind::TACAN => {
+                writeln!(out, "13 {} {:05} 130      0.000 {}", position, vhf, ident).unwrap()
+            }
+            NavAidKind::NDB => writeln!(
+                out,
+                " 2 {} {:5}  50      0.000 {}",
+                position, navaid.frequency_khz, ident
+            )
+            .unwrap(),
+            _ => (),
+        }
+    }
+    out += "99\n";
+    out
+}
+
+/// Produce `earth_awy.dat` (version 1100) from airways. Waypoints are resolved using `navaids`
+/// and `intersections`, and segments with a waypoint that can't be found are left out. `region`
+/// is the ICAO region the airways are in, such as `EG`.
+///
+/// Segments with a lower limit in the upper airspace, from FL 245, are written as high airways and
+/// all others as low airways. Only if the limits are missing are airways with a designator
+/// starting with `U` written as high airways. Segment limits are converted to flight levels.
+pub fn earth_awy(
+    airways: &[Airway],
+    navaids: &[NavAid],
+    intersections: &[Intersection],
+    region: &str,
+    airac: &AIRAC,
+) -> String {
+    let fixes = Fixes::new(navaids, intersections);
+    let mut out = header(1100, "AwyXP1100", airac);
+    for airway in airways {
+        for (from_wp, from, to_wp, to) in segments(airway, &fixes) {
+            let lower = flight_level(&from_wp.lower_limit);
+            let upper = flight_level(&from_wp.upper_limit);
+            let level = if is_high_segment(airway, from_wp, UPPER_AIRSPACE_FL) {
+                2
+            } else {
+                1
+            };
+            let row_type = |fix: super::Fix| match fix.navaid.map(|n| n.kind) {
+                None => AWY_FIX,
+                Some(NavAidKind::NDB) | Some(NavAidKind::Locator) => AWY_NDB,
+                Some(_) => AWY_VHF,
+            };
+            writeln!(
+                out,
+                "{:>5} {} {:2} {:>5} {} {:2} N {} {:3} {:3} {}",
+                from_wp.designator,
+                region,
+                row_type(from),
+                to_wp.designator,
+                region,
+                row_type(to),
+                level,
+                lower.unwrap_or(0),
+                upper.unwrap_or(600),
+                airway.designator
+            )
+            .unwrap();
+        }
+    }
+    out += "99\n";
+    out
+}
+
+/// The suffix X-Plane expects on the name of each kind of navaid.
+fn kind_name(kind: NavAidKind) -> &'static str {
+    match kind {
+        NavAidKind::VOR => "VOR",
+        NavAidKind::DME => "DME",
+        NavAidKind::VORDME => "VOR/DME",
+        NavAidKind::NDB => "NDB",
+        NavAidKind::TACAN => "TACAN",
+        NavAidKind::LOC => "LOC",
+        NavAidKind::GP => "GS",
+        NavAidKind::OM => "OM",
+        NavAidKind::MM => "MM",
+        NavAidKind::IM => "IM",
+        NavAidKind::Locator => "NDB",
+    }
+}
+
+lazy_static! {
+    pub(crate) static ref FL_RE: Regex = Regex::new(r"FL\s*(\d+)").unwrap();
+    pub(crate) static ref FT_RE: Regex = Regex::new(r"(\d+)\s*(?:FT|ft)").unwrap();
+}
+
+/// Convert an airway limit, such as `FL 195`, `5500 FT ALT` or `UNL`, to a flight level.
+pub(crate) fn flight_level(limit: &str) -> Option<usize> {
+    if let Some(caps) = FL_RE.captures(limit) {
+        caps[1].parse().ok()
+    } else if let Some(caps) = FT_RE.captures(limit) {
+        caps[1].parse::<usize>().ok().map(|ft| ft / 100)
+    } else if limit.contains("UNL") {
+        Some(600)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn airac() -> AIRAC {
+        AIRAC::from_ymd(2022, 5, 19)
+    }
+
+    #[test]
+    fn test_flight_level() {
+        assert_eq!(Some(195), flight_level("FL 195"));
+        assert_eq!(Some(245), flight_level("FL245"));
+        assert_eq!(Some(55), flight_level("5500 FT ALT"));
+        assert_eq!(Some(600), flight_level("UNL"));
+        assert_eq!(None, flight_level(""));
+    }
+
+    #[test]
+    fn test_earth_fix() {
+        let intersections = vec![Intersection {
+            designator: "ABBEW".to_string(),
+            latitude: 50.174444,
+            longitude: -1.063889,
+        }];
+        let out = earth_fix(&intersections, "EG", &airac());
+        assert!(out.starts_with("I\n1101 Version - data cycle 2205, build 20220519"));
+        assert!(out.contains("\n 50.174444000   -1.063889000 ABBEW ENRT EG 2105411\n"));
+        assert!(out.ends_with("\n99\n"));
+    }
+
+    #[test]
+    fn test_earth_nav() {
+        let navaids = vec![
+            NavAid {
+                id: "ADN".to_string(),
+                name: "ABERDEEN".to_string(),
+                kind: NavAidKind::VORDME,
+                frequency_khz: 114300,
+                latitude: 57.31,
+                longitude: -2.27,
+                elevation: 600,
+                ..Default::default()
+            },
+            NavAid {
+                id: "ATF".to_string(),
+                name: "ABERDEEN".to_string(),
+                kind: NavAidKind::NDB,
+                frequency_khz: 348,
+                latitude: 57.08,
+                longitude: -2.1,
+                elevation: 0,
+                ..Default::default()
+            },
+        ];
+        let out = earth_nav(&navaids, "EG", &airac());
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(
+            " 3  57.310000000   -2.270000000    600 11430 130      0.000 ADN ENRT EG ABERDEEN VOR/DME",
+            lines[3]
+        );
+        assert!(lines[4].starts_with("12 "));
+        assert_eq!(
+            " 2  57.080000000   -2.100000000      0   348  50      0.000 ATF ENRT EG ABERDEEN NDB",
+            lines[5]
+        );
+    }
+
+    #[test]
+    fn test_earth_awy() {
+        let navaids = vec![NavAid {
+            id: "ADN".to_string(),
+            ..Default::default()
+        }];
+        let intersections = vec![Intersection {
+            designator: "ABBEW".to_string(),
+            ..Default::default()
+        }];
+        let airways = vec![Airway {
+            designator: "UL9".to_string(),
+            waypoints: vec![
+                AirwayWaypoint {
+                    designator: "ADN".to_string(),
+                    lower_limit: "FL 245".to_string(),
+                    upper_limit: "UNL".to_string(),
+                },
+                AirwayWaypoint {
+                    designator: "ABBEW".to_string(),
+                    ..Default::default()
+                },
+            ],
+        }];
+        let out = earth_awy(&airways, &navaids, &intersections, "EG", &airac());
+        assert!(out.contains("\n  ADN EG  3 ABBEW EG 11 N 2 245 600 UL9\n"));
+
+        // Airways are high or low by their limits, and by designator only without limits.
+        let airway = |designator: &str, lower: &str, upper: &str| Airway {
+            designator: designator.to_string(),
+            waypoints: vec![
+                AirwayWaypoint {
+                    designator: "ADN".to_string(),
+                    lower_limit: lower.to_string(),
+                    upper_limit: upper.to_string(),
+                },
+                AirwayWaypoint {
+                    designator: "ABBEW".to_string(),
+                    ..Default::default()
+                },
+            ],
+        };
+        let airways = vec![
+            airway("L9", "FL 255", "FL 460"),
+            airway("UL10", "FL 75", "FL 195"),
+            airway("UL11", "", ""),
+        ];
+        let out = earth_awy(&airways, &navaids, &intersections, "EG", &airac());
+        assert!(out.contains("\n  ADN EG  3 ABBEW EG 11 N 2 255 460 L9\n"));
+        assert!(out.contains("\n  ADN EG  3 ABBEW EG 11 N 1  75 195 UL10\n"));
+        assert!(out.contains("\n  ADN EG  3 ABBEW EG 11 N 2   0 600 UL11\n"));
+    }
+
+    #[test]
+    fn test_parsed_coordinates() {
+        let navaids = Navaids::parse(
+            r#"<html><body><table><tbody><tr>
+            <td><strong>ABERDEEN</strong><p class="line">VOR/DME</p></td>
+            <td>ADN</td><td>114.300 MHz</td><td>H24</td>
+            <td>571839.00N 0021602.00W</td><td>600 FT</td>
+            </tr></tbody></table></body></html>"#,
+        )
+        .unwrap();
+        let out = earth_nav(&navaids, "EG", &airac());
+        assert!(out.contains("\n 3  57.310833333   -2.267222222    600 11430 "));
+    }
+}
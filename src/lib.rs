@@ -16,14 +16,33 @@ pub mod types;
 /// Error type
 pub mod error;
 
+/// Comparison of navigation data between AIRAC cycles.
+pub mod diff;
+
+/// Export of eAIP data to other formats.
+pub mod export;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
 /// A convenience module that imports many useful parts of this crate.
 pub mod prelude {
     pub use crate::parse::airports::Airports;
+    pub use crate::parse::airspaces::Airspaces;
     pub use crate::parse::airways::Airways;
+    pub use crate::parse::cycles::Cycles;
+    pub use crate::parse::geometry::BoundaryParser;
     pub use crate::parse::intersections::Intersections;
     pub use crate::parse::navaids::Navaids;
+    pub use crate::parse::restricted_areas::RestrictedAreas;
     pub use crate::parse::{FromEAIP, Parser};
 
+    pub use crate::diff::{Diff, Snapshot, SnapshotDiff};
+    pub use crate::eaip::builder::*;
+    pub use crate::eaip::cache::*;
+    pub use crate::eaip::http::*;
+    pub use crate::eaip::mirror::*;
+    pub use crate::eaip::source::*;
     pub use crate::eaip::*;
     pub use crate::error::*;
     pub use crate::parts::*;
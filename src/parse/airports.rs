@@ -1,10 +1,10 @@
 use regex::Regex;
-use scraper::{Html, Selector};
+use scraper::{ElementRef, Html, Selector};
 
 use crate::{parse::get_clean_text, prelude::*};
 use async_trait::async_trait;
 
-use super::{parse_elevation, parse_latlong};
+use super::{parse_elevation, parse_frequency, parse_latlong};
 
 /// A list of airport ICAO codes.
 pub type Airports = Vec<Airport>;
@@ -100,6 +100,102 @@ impl<'a> Parser<'a> for Airport {
                         let elevation = third_row.select(&data_td_selector).next().unwrap();
                         let clean = get_clean_text(elevation.inner_html());
                         airport.elevation = parse_elevation(&clean)?;
+                    } else if id.ends_with("-2.3") {
+                        // .<icao>-AD-2.3 contains the operational hours
+                        let hours = &mut airport.operational_hours;
+                        for (item, value) in parse_items(div) {
+                            let field = match item {
+                                1 => &mut hours.operator,
+                                2 => &mut hours.customs,
+                                3 => &mut hours.health,
+                                4 => &mut hours.ais_briefing,
+                                5 => &mut hours.ats_reporting,
+                                6 => &mut hours.met_briefing,
+                                7 => &mut hours.ats,
+                                8 => &mut hours.fuelling,
+                                9 => &mut hours.handling,
+                                10 => &mut hours.security,
+                                11 => &mut hours.de_icing,
+                                12 => &mut hours.remarks,
+                                _ => continue,
+                            };
+                            *field = value;
+                        }
+                        hours.has_customs = available(&hours.customs);
+                        hours.has_ais_briefing = available(&hours.ais_briefing);
+                    } else if id.ends_with("-2.4") {
+                        // .<icao>-AD-2.4 contains the handling services and facilities
+                        let handling = &mut airport.handling_services;
+                        for (item, value) in parse_items(div) {
+                            let field = match item {
+                                1 => &mut handling.cargo,
+                                2 => &mut handling.fuel,
+                                3 => &mut handling.fuelling,
+                                4 => &mut handling.de_icing,
+                                5 => &mut handling.hangar_space,
+                                6 => &mut handling.repair,
+                                7 => &mut handling.remarks,
+                                _ => continue,
+                            };
+                            *field = value;
+                        }
+                        handling.fuel_types = parse_fuel_types(&handling.fuel);
+                        handling.has_de_icing = available(&handling.de_icing);
+                    } else if id.ends_with("-2.5") {
+                        // .<icao>-AD-2.5 contains the passenger facilities
+                        let facilities = &mut airport.passenger_facilities;
+                        for (item, value) in parse_items(div) {
+                            let field = match item {
+                                1 => &mut facilities.hotels,
+                                2 => &mut facilities.restaurants,
+                                3 => &mut facilities.transportation,
+                                4 => &mut facilities.medical,
+                                5 => &mut facilities.bank_and_post_office,
+                                6 => &mut facilities.tourist_office,
+                                7 => &mut facilities.remarks,
+                                _ => continue,
+                            };
+                            *field = value;
+                        }
+                    } else if id.ends_with("-2.6") {
+                        // .<icao>-AD-2.6 contains the rescue and firefighting services
+                        let rescue = &mut airport.rescue_firefighting;
+                        for (item, value) in parse_items(div) {
+                            let field = match item {
+                                1 => &mut rescue.category,
+                                2 => &mut rescue.rescue_equipment,
+                                3 => &mut rescue.removal,
+                                4 => &mut rescue.remarks,
+                                _ => continue,
+                            };
+                            *field = value;
+                        }
+                        rescue.category_number = parse_rff_category(&rescue.category);
+                    } else if id.ends_with("-2.7") {
+                        // .<icao>-AD-2.7 contains the seasonal availability and clearing
+                        let seasonal = &mut airport.seasonal_availability;
+                        for (item, value) in parse_items(div) {
+                            let field = match item {
+                                1 => &mut seasonal.clearing_equipment,
+                                2 => &mut seasonal.clearance_priorities,
+                                3 => &mut seasonal.remarks,
+                                _ => continue,
+                            };
+                            *field = value;
+                        }
+                        seasonal.has_clearing = available(&seasonal.clearing_equipment);
+                    } else if id.ends_with("-2.12") {
+                        // .<icao>-AD-2.12 contains the runway physical characteristics
+                        airport.runways = parse_runways(div)?;
+                    } else if id.ends_with("-2.13") {
+                        // .<icao>-AD-2.13 contains the declared distances
+                        airport.declared_distances = parse_declared_distances(div);
+                    } else if id.ends_with("-2.18") {
+                        // .<icao>-AD-2.18 contains the ATS communication facilities
+                        airport.communications = parse_communications(div)?;
+                    } else if id.ends_with("-2.19") {
+                        // .<icao>-AD-2.19 contains the radio navigation and landing aids
+                        airport.navaids = parse_navaids(div)?;
                     } else if id.ends_with("-2.24") {
                         // .<icao>-ad-2.24 contains charts
                         // iterate through <td>, alternate between title and chart link
@@ -127,3 +223,508 @@ impl<'a> Parser<'a> for Airport {
         Ok(airport)
     }
 }
+
+/// Parse the numbered items from an AD 2 table with a row for each item, such as AD 2.3. The
+/// first cell is the item number and the last is its value. Values of `NIL` are left empty.
+/// Rows without a number are numbered after the row before.
+fn parse_items(div: ElementRef) -> Vec<(usize, String)> {
+    let row_selector = Selector::parse("tr").unwrap();
+    let td_selector = Selector::parse("td").unwrap();
+    let nil_re = Regex::new(r"(?i)^(?:NIL|NONE|-+)?\.?$").unwrap();
+
+    let mut items = Vec::new();
+    let mut last = 0;
+    for row in div.select(&row_selector) {
+        let cells = row
+            .select(&td_selector)
+            .map(|td| get_clean_text(td.inner_html()))
+            .collect::<Vec<_>>();
+        if cells.len() < 2 {
+            continue;
+        }
+        let item = cells[0]
+            .trim()
+            .trim_end_matches('.')
+            .parse::<usize>()
+            .unwrap_or(last + 1);
+        last = item;
+        let value = cells[cells.len() - 1]
+            .lines()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if nil_re.is_match(&value) {
+            items.push((item, String::new()));
+        } else {
+            items.push((item, value));
+        }
+    }
+    items
+}
+
+/// Is a service available, from how it's written in the eAIP?
+fn available(value: &str) -> bool {
+    let unavailable_re =
+        Regex::new(r"(?i)^(?:NIL|NONE|NO|NOT\s+AVBL|NOT\s+AVAILABLE|U/S|NA|N/A|-+)?\.?$").unwrap();
+    !unavailable_re.is_match(value.trim())
+}
+
+/// Parse the types of fuel, such as `AVGAS 100LL` and `JET A-1`, from the fuel and oil types
+/// of AD 2.4.
+fn parse_fuel_types(fuel: &str) -> Vec<String> {
+    let fuel_re = Regex::new(
+        r"(?i)\b(?:AVGAS(?:\s*\d+(?:\s*[A-Z]{1,2}\b)?)?|JET\s*A-?1?|MOGAS|AVTUR|AVCAT|UL\s*91|F-?3[45]|JP-?\d+)",
+    )
+    .unwrap();
+    let mut types = Vec::new();
+    for m in fuel_re.find_iter(fuel) {
+        let fuel = m.as_str().split_whitespace().collect::<Vec<_>>().join(" ");
+        if !types.contains(&fuel) {
+            types.push(fuel);
+        }
+    }
+    types
+}
+
+/// Parse the aerodrome category for firefighting, from 1 to 10, from AD 2.6.
+fn parse_rff_category(category: &str) -> Option<u8> {
+    let category_re =
+        Regex::new(r"(?i)(?:\bCAT(?:EGORY)?|\bRFF|\bA)\s*:?\s*(\d{1,2})\b|^(\d{1,2})\b").unwrap();
+    category_re
+        .captures(category)
+        .and_then(|caps| caps.get(1).or_else(|| caps.get(2)))
+        .and_then(|m| m.as_str().parse().ok())
+        .filter(|c| (1..=10).contains(c))
+}
+
+/// Parse the runways from the AD 2.12 table, which has a row for each runway direction. The
+/// columns are the designator, bearing, dimensions, strength and surface, threshold
+/// coordinates, then threshold and touchdown zone elevations. Some eAIPs continue the table
+/// with more columns in a second table, which is ignored.
+fn parse_runways(div: ElementRef) -> Result<Vec<Runway>> {
+    let row_selector = Selector::parse("tr").unwrap();
+    let td_selector = Selector::parse("td").unwrap();
+    let designator_re = Regex::new(r"^(?:RWY\s*)?(\d{2}[LRC]?)$").unwrap();
+    let bearing_re = Regex::new(r"(\d{1,3}(?:\.\d+)?)\s*°?\s*(MAG|M|T|TRUE|GEO)?\b").unwrap();
+    let dimensions_re = Regex::new(r"(\d+)\s*[xX×]\s*(\d+)").unwrap();
+    let pcn_re = Regex::new(r"PCN\s*[\d.]+\s*/?\s*[A-Z](?:\s*/\s*[A-Z]){3}").unwrap();
+    let coord_re = Regex::new(r"\d{6}(?:\.\d+)?[NS]\s*\d{7}(?:\.\d+)?[EW]").unwrap();
+    let elevation_re = Regex::new(r"(?i)(-?\d+(?:\.\d+)?)\s*FT").unwrap();
+
+    let mut runways: Vec<Runway> = Vec::new();
+    for row in div.select(&row_selector) {
+        let cells = row
+            .select(&td_selector)
+            .map(|td| get_clean_text(td.inner_html()))
+            .collect::<Vec<_>>();
+        if cells.len() < 6 {
+            continue;
+        }
+        let designator = match designator_re.captures(cells[0].trim()) {
+            Some(caps) => caps[1].to_string(),
+            None => continue,
+        };
+        if runways.iter().any(|r| r.designator == designator) {
+            continue;
+        }
+        let mut runway = Runway {
+            designator,
+            ..Default::default()
+        };
+
+        let mut true_bearing = None;
+        for caps in bearing_re.captures_iter(&cells[1]) {
+            let bearing = caps[1].parse::<f64>().unwrap();
+            match caps.get(2).map(|m| m.as_str()) {
+                Some("MAG") | Some("M") => runway.magnetic_bearing = Some(bearing),
+                _ if true_bearing.is_none() => true_bearing = Some(bearing),
+                _ => (),
+            }
+        }
+        runway.true_bearing = true_bearing.unwrap_or_default();
+
+        if let Some(caps) = dimensions_re.captures(&cells[2]) {
+            runway.length = caps[1].parse().unwrap();
+            runway.width = caps[2].parse().unwrap();
+        }
+
+        // The strength is given as a PCN, and the surface is the rest of the cell.
+        let mut surface = Vec::new();
+        for line in cells[3].lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+            match pcn_re.find(line) {
+                Some(m) if runway.strength.is_empty() => {
+                    runway.strength = m.as_str().to_string();
+                    let rest = line.replace(m.as_str(), "");
+                    let rest = rest.trim_matches(|c: char| c.is_whitespace() || c == ',');
+                    if !rest.is_empty() {
+                        surface.push(rest.to_string());
+                    }
+                }
+                _ => surface.push(line.to_string()),
+            }
+        }
+        runway.surface = surface.join(" ");
+
+        if let Some(m) = coord_re.find(&cells[4]) {
+            if let (Some(lat), Some(lon)) = parse_latlong(m.as_str())? {
+                runway.threshold_latitude = lat;
+                runway.threshold_longitude = lon;
+            }
+        }
+
+        // The first elevation is the threshold, and one labelled TDZ is the touchdown zone.
+        let mut threshold_elevation = None;
+        for line in cells[5].lines() {
+            if let Some(caps) = elevation_re.captures(line) {
+                let elevation = caps[1].parse::<f64>().unwrap();
+                if line.to_uppercase().contains("TDZ") {
+                    runway.tdz_elevation = Some(elevation);
+                } else if threshold_elevation.is_none() {
+                    threshold_elevation = Some(elevation);
+                }
+            }
+        }
+        runway.threshold_elevation = threshold_elevation.unwrap_or_default();
+
+        runways.push(runway);
+    }
+
+    Ok(runways)
+}
+
+/// Parse the declared distances from the AD 2.13 table, which has a row for each runway
+/// direction and intersection departure. The columns are the designator, TORA, TODA, ASDA, LDA
+/// and remarks. The intersection is given either after the designator, or in the remarks.
+fn parse_declared_distances(div: ElementRef) -> Vec<DeclaredDistances> {
+    let row_selector = Selector::parse("tr").unwrap();
+    let td_selector = Selector::parse("td").unwrap();
+    let designator_re = Regex::new(r"^(?:RWY\s*)?(\d{2}[LRC]?)\b\s*(.*)$").unwrap();
+    let intersection_re =
+        Regex::new(r"(?i)\b(?:TWY|taxiway|holding point|HP)\s*[A-Z]\d*[A-Z]?\d*\b").unwrap();
+    let remarks_re = Regex::new(&format!(
+        r"(?i)(?:\bfrom|\bintersection(?:\s+with)?|\bINT)\s+({0})|({0})\s+intersection",
+        intersection_re.as_str().trim_start_matches("(?i)")
+    ))
+    .unwrap();
+    let distance_re = Regex::new(r"^(\d+)\s*(?:M)?$").unwrap();
+    let nil_re = Regex::new(r"(?i)^(?:NIL|NONE|-+)?$").unwrap();
+
+    let mut distances: Vec<DeclaredDistances> = Vec::new();
+    for row in div.select(&row_selector) {
+        let cells = row
+            .select(&td_selector)
+            .map(|td| get_clean_text(td.inner_html()).trim().to_string())
+            .collect::<Vec<_>>();
+        if cells.len() < 5 {
+            continue;
+        }
+
+        // Intersection departures may leave out the designator of the runway.
+        let (runway, rest) = match designator_re.captures(&cells[0]) {
+            Some(caps) => (caps[1].to_string(), caps[2].to_string()),
+            None => match (distances.last(), intersection_re.is_match(&cells[0])) {
+                (Some(last), true) => (last.runway.clone(), cells[0].clone()),
+                _ => continue,
+            },
+        };
+        let distance = |cell: &String| {
+            distance_re
+                .captures(cell)
+                .map(|caps| Metres(caps[1].parse().unwrap()))
+        };
+        let remarks = cells
+            .get(5)
+            .map(|c| c.lines().map(|l| l.trim()).collect::<Vec<_>>().join(" "))
+            .filter(|c| !nil_re.is_match(c))
+            .unwrap_or_default();
+        let rest = rest.trim_matches(|c: char| c.is_whitespace() || "()-,".contains(c));
+        let intersection = match intersection_re.find(rest) {
+            Some(m) => Some(m.as_str().to_string()),
+            None if !rest.is_empty() => Some(rest.to_string()),
+            None => remarks_re
+                .captures(&remarks)
+                .and_then(|caps| caps.get(1).or_else(|| caps.get(2)))
+                .map(|m| m.as_str().to_string()),
+        };
+
+        distances.push(DeclaredDistances {
+            runway,
+            intersection,
+            tora: distance(&cells[1]),
+            toda: distance(&cells[2]),
+            asda: distance(&cells[3]),
+            lda: distance(&cells[4]),
+            remarks,
+        });
+    }
+
+    distances
+}
+
+/// Parse the ATS communication facilities from the AD 2.18 table, which has a row for each
+/// service. The columns are the service designation, call sign and frequencies, sometimes
+/// followed by SATVOICE and logon addresses, then the hours of operation and remarks. Rows
+/// without a service designation continue the service above.
+fn parse_communications(div: ElementRef) -> Result<Vec<CommunicationService>> {
+    fn lines(cell: &str) -> Vec<&str> {
+        cell.lines()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty())
+            .collect()
+    }
+
+    let row_selector = Selector::parse("tr").unwrap();
+    let td_selector = Selector::parse("td").unwrap();
+    let mhz_re = Regex::new(r"^(\d{3}\.\d{1,3})\s*(?:MHz)?\b").unwrap();
+    let frequency_re = Regex::new(r"\d[\d.]*\s*[kM]Hz|^\d{3}\.\d{1,3}\b").unwrap();
+    let nil_re = Regex::new(r"(?i)^(?:NIL|NONE|-+)?$").unwrap();
+    let kinds = [
+        (r"\bATIS\b", CommunicationServiceKind::ATIS),
+        (
+            r"\b(?:RAD|RADAR|DIRECTOR|PAR|SRA)\b",
+            CommunicationServiceKind::Radar,
+        ),
+        (r"\b(?:APP|APPROACH)\b", CommunicationServiceKind::Approach),
+        (r"\b(?:TWR|TOWER)\b", CommunicationServiceKind::Tower),
+        (r"\b(?:GND|GMC|GROUND)\b", CommunicationServiceKind::Ground),
+        (
+            r"\b(?:DEL|DELIVERY|CLNC|CLEARANCE)\b",
+            CommunicationServiceKind::Delivery,
+        ),
+        (
+            r"\b(?:AFIS|FIS|INFO|INFORMATION|A/G|RADIO)\b",
+            CommunicationServiceKind::Information,
+        ),
+    ]
+    .map(|(re, kind)| (Regex::new(&format!("(?i){}", re)).unwrap(), kind));
+
+    let mut services: Vec<CommunicationService> = Vec::new();
+    for row in div.select(&row_selector) {
+        let cells = row
+            .select(&td_selector)
+            .map(|td| get_clean_text(td.inner_html()).trim().to_string())
+            .collect::<Vec<_>>();
+        if cells.len() < 5 {
+            continue;
+        }
+
+        let mut frequencies = Vec::new();
+        for line in lines(&cells[2]) {
+            if !frequency_re.is_match(line) {
+                continue;
+            }
+            // Frequencies are sometimes given without their unit.
+            let frequency = match mhz_re.captures(line) {
+                Some(caps) => parse_frequency(format!("{} MHz", &caps[1]))?,
+                None => parse_frequency(line)?,
+            };
+            if !frequencies.contains(&frequency) {
+                frequencies.push(frequency);
+            }
+        }
+        let text = |cell: &String| {
+            Some(lines(cell).join(" "))
+                .filter(|t| !nil_re.is_match(t))
+                .unwrap_or_default()
+        };
+        let hours = text(&cells[cells.len() - 2]);
+        let remarks = text(&cells[cells.len() - 1]);
+
+        if cells[0].is_empty() {
+            if let Some(service) = services.last_mut() {
+                for frequency in frequencies {
+                    if !service.frequencies_khz.contains(&frequency) {
+                        service.frequencies_khz.push(frequency);
+                    }
+                }
+                for (field, value) in [(&mut service.hours, hours), (&mut service.remarks, remarks)]
+                {
+                    if !value.is_empty() && !field.contains(&value) {
+                        if !field.is_empty() {
+                            field.push(' ');
+                        }
+                        field.push_str(&value);
+                    }
+                }
+            }
+            continue;
+        }
+
+        let service = lines(&cells[0]).join(" ");
+        let call_sign = lines(&cells[1]).join(" ");
+        let kind = kinds
+            .iter()
+            .find(|(re, _)| re.is_match(&service))
+            .or_else(|| kinds.iter().find(|(re, _)| re.is_match(&call_sign)))
+            .map(|(_, kind)| *kind)
+            .unwrap_or_default();
+        services.push(CommunicationService {
+            service,
+            kind,
+            call_sign,
+            frequencies_khz: frequencies,
+            hours,
+            remarks,
+        });
+    }
+
+    Ok(services)
+}
+
+/// Parse the radio navigation and landing aids from the AD 2.19 table, which has a row for
+/// each aid. The columns are the type of aid (often with the runway it serves), identification,
+/// frequency or channel, hours of operation, position, then elevation, service volume and
+/// remarks, with the remarks last.
+fn parse_navaids(div: ElementRef) -> Result<Vec<NavAid>> {
+    let row_selector = Selector::parse("tr").unwrap();
+    let td_selector = Selector::parse("td").unwrap();
+    let kinds = [
+        (r"\b(?:GP|GS)\b|GLIDE\s*PATH|GLIDE\s*SLOPE", NavAidKind::GP),
+        (r"\bOM\b|OUTER\s+MARKER", NavAidKind::OM),
+        (r"\bMM\b|MIDDLE\s+MARKER", NavAidKind::MM),
+        (r"\bIM\b|INNER\s+MARKER", NavAidKind::IM),
+        (r"\b(?:LOC|LLZ|ILS)\b|LOCALI[SZ]ER", NavAidKind::LOC),
+        (r"\bVOR\s*/?\s*DME\b", NavAidKind::VORDME),
+        (r"\bVOR\b", NavAidKind::VOR),
+        (r"\bDME\b", NavAidKind::DME),
+        (r"\bTACAN\b", NavAidKind::TACAN),
+        (r"\b(?:L|NDB)\b|LOCATOR", NavAidKind::Locator),
+    ]
+    .map(|(re, kind)| (Regex::new(&format!("(?i){}", re)).unwrap(), kind));
+    let runway_re = Regex::new(r"(?i)\bRWY\s*(\d{2}[LRC]?)\b").unwrap();
+    let aid_runway_re =
+        Regex::new(r"(?i)^(?:ILS\s*/\s*)?(?:GP|GS|LOC|LLZ|OM|MM|IM)\s+(\d{2}[LRC]?)\b").unwrap();
+    let course_re =
+        Regex::new(r"(?i)\b(?:course|CRS|QDM|bearing|BRG)\s*:?\s*(\d{1,3}(?:\.\d+)?)\s*°").unwrap();
+    let angle_re = Regex::new(r"\b(\d(?:\.\d{1,2})?)\s*°\s*([EW])?").unwrap();
+    let frequency_re = Regex::new(r"[\d.]+\s*[kM]Hz").unwrap();
+    let channel_re = Regex::new(r"CH\s*(\d+[XY])").unwrap();
+    let coord_re = Regex::new(r"\d{6}(?:\.\d+)?[NS]\s*\d{7}(?:\.\d+)?[EW]").unwrap();
+    let elevation_re = Regex::new(r"(?i)(-?\d+(?:\.\d+)?)\s*FT").unwrap();
+    let nil_re = Regex::new(r"(?i)^(?:NIL|NONE|-+)?$").unwrap();
+
+    let mut navaids = Vec::new();
+    for row in div.select(&row_selector) {
+        let cells = row
+            .select(&td_selector)
+            .map(|td| get_clean_text(td.inner_html()).trim().to_string())
+            .collect::<Vec<_>>();
+        if cells.len() < 5 {
+            continue;
+        }
+        let description = cells[0].split_whitespace().collect::<Vec<_>>().join(" ");
+        let kind = match kinds.iter().find(|(re, _)| re.is_match(&description)) {
+            Some((_, kind)) => *kind,
+            None => continue,
+        };
+        let remarks = &cells[cells.len() - 1];
+
+        let mut navaid = NavAid {
+            id: Some(cells[1].lines().next().unwrap_or_default().trim())
+                .filter(|id| !nil_re.is_match(id))
+                .unwrap_or_default()
+                .to_string(),
+            name: description.clone(),
+            kind,
+            ..Default::default()
+        };
+
+        if let Some(m) = frequency_re.find(&cells[2]) {
+            navaid.frequency_khz = parse_frequency(m.as_str())?;
+        }
+        if let Some(caps) = channel_re.captures(&cells[2]) {
+            let channel = format!("CH{}", &caps[1]);
+            if navaid.frequency_khz == 0 {
+                navaid.frequency_khz = parse_frequency(&channel)?;
+            }
+            navaid.channel = Some(channel);
+        }
+
+        if let Some(m) = coord_re.find(&cells[4]) {
+            if let (Some(lat), Some(lon)) = parse_latlong(m.as_str())? {
+                navaid.latitude = lat;
+                navaid.longitude = lon;
+            }
+        }
+        if let Some(caps) = cells.get(5).and_then(|c| elevation_re.captures(c)) {
+            navaid.elevation = caps[1].parse::<f64>().unwrap().round() as isize;
+        }
+
+        // The runway is labelled, or follows the type of aid as in `GP 16`. Otherwise, the
+        // glidepath and markers of an ILS serve the runway of the localiser listed before them.
+        navaid.runway = runway_re
+            .captures(&description)
+            .or_else(|| aid_runway_re.captures(&description))
+            .or_else(|| runway_re.captures(remarks))
+            .map(|caps| caps[1].to_string());
+        if navaid.runway.is_none()
+            && matches!(
+                kind,
+                NavAidKind::GP | NavAidKind::OM | NavAidKind::MM | NavAidKind::IM
+            )
+        {
+            navaid.runway = navaids
+                .iter()
+                .rev()
+                .find(|n: &&NavAid| n.kind == NavAidKind::LOC)
+                .and_then(|n| n.runway.clone());
+        }
+        if kind == NavAidKind::LOC {
+            navaid.course = course_re
+                .captures(&description)
+                .or_else(|| course_re.captures(remarks))
+                .map(|caps| caps[1].parse().unwrap());
+        } else if kind == NavAidKind::GP {
+            // The angle is the first in degrees that isn't a magnetic variation.
+            navaid.glidepath_angle = angle_re
+                .captures_iter(&description)
+                .chain(angle_re.captures_iter(remarks))
+                .find(|caps| caps.get(2).is_none())
+                .map(|caps| caps[1].parse().unwrap());
+        }
+
+        navaids.push(navaid);
+    }
+
+    Ok(navaids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{available, parse_fuel_types, parse_rff_category};
+
+    #[test]
+    fn test_parse_fuel_types() {
+        assert_eq!(
+            vec!["AVGAS 100LL", "JET A-1"],
+            parse_fuel_types("AVGAS 100LL, JET A-1.")
+        );
+        assert_eq!(vec!["AVGAS", "JET A-1"], parse_fuel_types("AVGAS, JET A-1"));
+        assert_eq!(
+            vec!["AVGAS", "JET A-1"],
+            parse_fuel_types("AVGAS and JET A-1")
+        );
+        assert_eq!(
+            vec!["AVGAS 100 LL", "MOGAS"],
+            parse_fuel_types("AVGAS 100 LL and MOGAS")
+        );
+        assert!(parse_fuel_types("NIL").is_empty());
+    }
+
+    #[test]
+    fn test_parse_rff_category() {
+        assert_eq!(Some(7), parse_rff_category("RFF Category 7."));
+        assert_eq!(Some(3), parse_rff_category("CAT 3"));
+        assert_eq!(Some(10), parse_rff_category("10"));
+        assert_eq!(None, parse_rff_category("NIL"));
+    }
+
+    #[test]
+    fn test_available() {
+        assert!(available("H24"));
+        assert!(!available("NIL"));
+        assert!(!available("Not AVBL."));
+        assert!(!available(""));
+    }
+}
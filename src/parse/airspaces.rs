@@ -0,0 +1,172 @@
+use async_trait::async_trait;
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+
+use crate::parse::geometry::BoundaryParser;
+use crate::parse::{get_clean_text, parse_frequency, parse_vertical_limit};
+use crate::prelude::*;
+
+/// A list of ATS airspaces that can be parsed with a [`Parser`] from data from
+/// an [`EAIP`](crate::eaip::EAIP).
+pub type Airspaces = Vec<Airspace>;
+
+#[async_trait]
+impl FromEAIP for Airspaces {
+    type Output = Self;
+
+    async fn from_eaip(eaip: &EAIP, airac: airac::AIRAC) -> Result<Self::Output> {
+        let page = Part::EnRoute(ENR::ATSAirspace(1));
+        let data = eaip.get_page(airac, page, EAIPType::HTML).await?;
+        let airspaces = Airspaces::parse(&data)?;
+        Ok(airspaces)
+    }
+}
+
+impl<'a> Parser<'a> for Airspaces {
+    type Output = Self;
+
+    fn parse(data: &'a str) -> Result<Self::Output> {
+        let html = Html::parse_document(data);
+        let row_selector = Selector::parse("table > tbody > tr").unwrap();
+        let upper_selector = Selector::parse("td.Upper").unwrap();
+        let lower_selector = Selector::parse("td.Lower").unwrap();
+        let kind_re = Regex::new(r"\b(FIR|UIR|TMA|CTA)\b").unwrap();
+        let coord_re = Regex::new(r"\d{6}(?:\.\d+)?[NS]\s*\d{7}(?:\.\d+)?[EW]").unwrap();
+        let class_re = Regex::new(r"^(?:(?i:class)\s*:?\s*)?([A-G](?:\s*[/,]\s*[A-G])*)$").unwrap();
+        let boundary_parser = BoundaryParser::new();
+
+        let mut airspaces: Vec<Airspace> = Vec::new();
+        for row in html.select(&row_selector) {
+            // Skip the rows of tables nested within cells, such as vertical limits.
+            if row
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(|e| e.value().name() == "td")
+            {
+                continue;
+            }
+            let cells = row
+                .children()
+                .filter_map(ElementRef::wrap)
+                .filter(|e| e.value().name() == "td")
+                .collect::<Vec<_>>();
+            if cells.is_empty() {
+                continue;
+            }
+
+            let first = get_clean_text(cells[0].inner_html());
+            let mut lines = first.lines().map(|l| l.trim()).filter(|l| !l.is_empty());
+            let name = lines.next().unwrap_or_default();
+            let starts_airspace = !name.is_empty()
+                && (kind_re.is_match(name) || coord_re.is_match(&first))
+                && !coord_re.is_match(name);
+
+            if starts_airspace {
+                let mut airspace = Airspace {
+                    name: name.to_string(),
+                    ..Default::default()
+                };
+                if let Some(caps) = kind_re.captures(name) {
+                    airspace.kind = match &caps[1] {
+                        "FIR" => AirspaceKind::FIR,
+                        "UIR" => AirspaceKind::UIR,
+                        "TMA" => AirspaceKind::TMA,
+                        _ => AirspaceKind::CTA,
+                    };
+                }
+
+                // Vertical limits are usually given in a small table, but fall back to the
+                // first two limits in the text.
+                if let Some(upper) = cells[0].select(&upper_selector).next() {
+                    airspace.upper_limit = get_clean_text(upper.inner_html());
+                }
+                if let Some(lower) = cells[0].select(&lower_selector).next() {
+                    airspace.lower_limit = get_clean_text(lower.inner_html());
+                }
+
+                let mut lateral = Vec::new();
+                let mut limits = Vec::new();
+                for line in lines {
+                    if coord_re.is_match(line) || line.starts_with('-') || line.ends_with('-') {
+                        lateral.push(line);
+                    } else if let Some(caps) = class_re.captures(line) {
+                        airspace.class = caps[1].to_string();
+                    } else if let Some(limit) = parse_vertical_limit(line) {
+                        limits.push(limit);
+                    } else if !lateral.is_empty() && limits.is_empty() {
+                        // Lateral limits can be described in words, such as along a border.
+                        lateral.push(line);
+                    }
+                }
+                if airspace.upper_limit.is_empty() && airspace.lower_limit.is_empty() {
+                    let mut limits = limits.into_iter();
+                    airspace.upper_limit = limits.next().unwrap_or_default();
+                    airspace.lower_limit = limits.next().unwrap_or_default();
+                }
+                airspace.lateral_limits = lateral.join(" ");
+                let boundary = boundary_parser.parse(&airspace.lateral_limits)?;
+                airspace.boundary = boundary.vertices;
+                airspace.unparsed_limits = boundary.unparsed;
+                airspaces.push(airspace);
+            }
+
+            // Other cells give the services in the latest airspace, also in rows of their own.
+            if let Some(airspace) = airspaces.last_mut() {
+                add_services(airspace, &cells[1..], &class_re);
+            }
+        }
+
+        Ok(airspaces)
+    }
+}
+
+/// Add the class, call signs and frequencies from the cells following the first of a row.
+/// These are the unit, call sign, frequency and remarks columns, in that order, although the
+/// class is sometimes given in a column of its own, straight after the first, or labelled as a
+/// class in another column.
+fn add_services(airspace: &mut Airspace, cells: &[ElementRef], class_re: &Regex) {
+    let mut cells = cells
+        .iter()
+        .map(|cell| get_clean_text(cell.inner_html()))
+        .collect::<Vec<_>>();
+
+    if let Some(i) = cells.iter().enumerate().position(|(i, c)| {
+        let c = c.trim();
+        (i == 0 || c.to_lowercase().starts_with("class")) && class_re.is_match(c)
+    }) {
+        if airspace.class.is_empty() {
+            airspace.class = class_re.captures(cells[i].trim()).unwrap()[1].to_string();
+        }
+        cells.remove(i);
+    }
+
+    if let Some(call_sign) = cells
+        .get(1)
+        .and_then(|c| c.lines().map(|l| l.trim()).find(|l| !l.is_empty()))
+    {
+        if !airspace.call_signs.iter().any(|c| c == call_sign) {
+            airspace.call_signs.push(call_sign.to_string());
+        }
+    }
+    if let Some(frequencies) = cells.get(2) {
+        for line in frequencies
+            .lines()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty())
+        {
+            // Frequencies are sometimes given without a unit, in which case they are in MHz.
+            let khz = if line.contains("Hz") {
+                parse_frequency(line)
+            } else {
+                parse_frequency(format!("{} MHz", line.split_whitespace().next().unwrap()))
+            };
+            let khz = match khz {
+                Ok(khz) => khz,
+                Err(_) => continue,
+            };
+            if !airspace.frequencies_khz.contains(&khz) {
+                airspace.frequencies_khz.push(khz);
+            }
+        }
+    }
+}
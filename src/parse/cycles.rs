@@ -0,0 +1,141 @@
+use airac::{Datelike, NaiveDate, AIRAC};
+use regex::Regex;
+use scraper::{Html, Selector};
+
+use crate::{parse::get_clean_text, prelude::*};
+
+/// A list of published AIRAC cycles that can be parsed with a [`Parser`] from the history page
+/// of an [`EAIP`](crate::eaip::EAIP). Cycles are sorted by effective date, oldest first.
+///
+/// Each cycle is read from a table row or list item linking to its package, as on the standard
+/// eAIP history page and the LVNL index.
+pub type Cycles = Vec<Cycle>;
+
+impl<'a> Parser<'a> for Cycles {
+    type Output = Self;
+
+    fn parse(data: &'a str) -> Result<Self::Output> {
+        let html = Html::parse_document(data);
+        let entry_selector = Selector::parse("tr, li").unwrap();
+        let a_selector = Selector::parse("a[href]").unwrap();
+        let folder_re = Regex::new(r"(\d{4})-(\d{2})-(\d{2})-AIRAC").unwrap();
+
+        let mut cycles = Cycles::new();
+        for row in html.select(&entry_selector) {
+            // Only read the innermost entries, so the dates of nested entries aren't mixed up.
+            let nested = row
+                .descendants()
+                .skip(1)
+                .any(|n| matches!(n.value().as_element().map(|e| e.name()), Some("tr" | "li")));
+            if nested {
+                continue;
+            }
+            let folder = row.select(&a_selector).find_map(|a| {
+                let href = a.value().attr("href").unwrap();
+                folder_re.captures(href).map(|caps| {
+                    let date = NaiveDate::from_ymd_opt(
+                        caps[1].parse().unwrap(),
+                        caps[2].parse().unwrap(),
+                        caps[3].parse().unwrap(),
+                    );
+                    (caps[0].to_string(), date)
+                })
+            });
+            let (folder, folder_date) = match folder {
+                Some((folder, Some(date))) => (folder, date),
+                _ => continue,
+            };
+            let location = format!("/{}", folder);
+            if cycles.iter().any(|c: &Cycle| c.location == location) {
+                continue;
+            }
+
+            // The effective date is the date in the row that an AIRAC starts on. Otherwise, the
+            // package is published in advance, so it is effective from the next AIRAC.
+            let dates = parse_dates(&get_clean_text(row.inner_html()));
+            let effective = dates
+                .iter()
+                .copied()
+                .find(|d| is_airac_start(*d))
+                .unwrap_or_else(|| {
+                    if is_airac_start(folder_date) {
+                        folder_date
+                    } else {
+                        airac_on(folder_date).next().starts()
+                    }
+                });
+            let published = dates.iter().copied().find(|d| *d < effective);
+
+            cycles.push(Cycle {
+                airac: airac_on(effective),
+                effective,
+                published,
+                location,
+            });
+        }
+
+        if cycles.is_empty() {
+            return Err(Error::CannotScrapeData("no cycles are listed"));
+        }
+        cycles.sort_by_key(|c| c.effective);
+        Ok(cycles)
+    }
+}
+
+/// The AIRAC that is effective on a date.
+fn airac_on(date: NaiveDate) -> AIRAC {
+    AIRAC::from_ymd(date.year(), date.month(), date.day())
+}
+
+/// Does an AIRAC start on this date?
+fn is_airac_start(date: NaiveDate) -> bool {
+    airac_on(date).starts() == date
+}
+
+/// Find all dates in some text, in the formats `16 JUN 2022`, `16-JUN-2022` and `2022-06-16`.
+fn parse_dates(text: &str) -> Vec<NaiveDate> {
+    let re = Regex::new(r"(\d{1,2})[ -]([A-Za-z]{3})[A-Za-z]*[ -](\d{4})|(\d{4})-(\d{2})-(\d{2})")
+        .unwrap();
+    let months = [
+        "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+    ];
+
+    re.captures_iter(text)
+        .filter_map(|caps| {
+            if let Some(day) = caps.get(1) {
+                let month = months.iter().position(|m| *m == caps[2].to_uppercase())?;
+                NaiveDate::from_ymd_opt(
+                    caps[3].parse().ok()?,
+                    month as u32 + 1,
+                    day.as_str().parse().ok()?,
+                )
+            } else {
+                NaiveDate::from_ymd_opt(
+                    caps[4].parse().ok()?,
+                    caps[5].parse().ok()?,
+                    caps[6].parse().ok()?,
+                )
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use airac::NaiveDate;
+
+    use super::parse_dates;
+
+    #[test]
+    fn test_parse_dates() {
+        assert_eq!(
+            vec![
+                NaiveDate::from_ymd_opt(2022, 6, 16).unwrap(),
+                NaiveDate::from_ymd_opt(2022, 5, 5).unwrap(),
+                NaiveDate::from_ymd_opt(2022, 7, 14).unwrap(),
+            ],
+            parse_dates("16 JUN 2022 05-May-2022 2022-07-14")
+        );
+        assert!(parse_dates("AIRAC 07/22").is_empty());
+    }
+}
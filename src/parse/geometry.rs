@@ -0,0 +1,374 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::parse_latlong;
+use crate::prelude::*;
+
+/// The mean radius of the Earth, in nautical miles.
+const EARTH_RADIUS_NM: f64 = 3440.065;
+
+/// The default maximum angle, in degrees, between the vertices of circles and arcs.
+pub const DEFAULT_ARC_STEP: f64 = 10f64;
+
+/// A coordinate in the lateral limits, such as `513012N 0001234W`.
+const COORD: &str = r"\d{6}(?:\.\d+)?[NS]\s*\d{7}(?:\.\d+)?[EW]";
+
+/// The radius and centre of a circle or arc, such as `2 NM radius, centred at`.
+const RADIUS: &str = r"([\d.]+)\s*(NM|KM|M)\b\s*(?:radius,?\s*)?cent(?:red|ered)\s+(?:at|on)\s+";
+
+lazy_static! {
+    static ref CIRCLE_RE: Regex = Regex::new(&format!(
+        r"(?i)\bcircle,?\s*(?:of\s+)?(?:radius\s+)?{}({})",
+        RADIUS, COORD
+    ))
+    .unwrap();
+    static ref ARC_RE: Regex = Regex::new(&format!(
+        r"(?i)\b(clockwise|anti-?clockwise|counter-?clockwise)\s+(?:by\s+)?(?:the\s+)?arc\s+of\s+(?:a\s+)?circle,?\s*(?:of\s+)?(?:radius\s+)?{}({})(?:\s*\([^)]*\))?\s*,?\s*to\s+({})",
+        RADIUS, COORD, COORD
+    ))
+    .unwrap();
+    static ref POINT_RE: Regex = Regex::new(COORD).unwrap();
+    static ref NOISE_RE: Regex = Regex::new(
+        r"(?i)\b(?:a|and|thence|then|to|the|point|of|origin|beginning|start)\b|[\s\-–,.;:()]",
+    )
+    .unwrap();
+    static ref ENDS_RE: Regex = Regex::new(
+        r"(?i)^(?:[\s\-–,.;:]|\b(?:thence|then|to)\b)+|(?:[\s\-–,.;:]|\b(?:thence|then|to)\b)+$"
+    )
+    .unwrap();
+}
+
+/// The point at a distance (in nautical miles) and true bearing (in degrees) from a point, as
+/// (latitude, longitude).
+pub(crate) fn destination(from: (f64, f64), bearing: f64, distance_nm: f64) -> (f64, f64) {
+    let (lat, lon) = (from.0.to_radians(), from.1.to_radians());
+    let bearing = bearing.to_radians();
+    let d = distance_nm / EARTH_RADIUS_NM;
+    let lat2 = (lat.sin() * d.cos() + lat.cos() * d.sin() * bearing.cos()).asin();
+    let lon2 = lon + (bearing.sin() * d.sin() * lat.cos()).atan2(d.cos() - lat.sin() * lat2.sin());
+    (
+        lat2.to_degrees(),
+        (lon2.to_degrees() + 540f64) % 360f64 - 180f64,
+    )
+}
+
+/// The initial true bearing (in degrees) from one point to another.
+pub(crate) fn bearing(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (lat1, lat2) = (from.0.to_radians(), to.0.to_radians());
+    let dlon = (to.1 - from.1).to_radians();
+    let y = dlon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlon.cos();
+    y.atan2(x).to_degrees().rem_euclid(360f64)
+}
+
+/// Convert a distance to nautical miles.
+fn to_nm(value: f64, unit: &str) -> f64 {
+    match unit.to_uppercase().as_str() {
+        "KM" => value / 1.852,
+        "M" => value / 1852f64,
+        _ => value,
+    }
+}
+
+/// A parser for the lateral limits of airspaces and areas, such as
+/// `A circle, 2 NM radius, centred at 513012N 0001234W`, or a list of points joined by arcs
+/// like `thence clockwise by the arc of a circle radius 10 NM centred on 513012N 0001234W to
+/// 510000N 0010000W`. Circles and arcs are approximated by vertices no more than
+/// [`arc_step`](Self::with_arc_step) degrees apart, as seen from their centre.
+#[derive(Debug, Clone, Copy)]
+pub struct BoundaryParser {
+    arc_step: f64,
+}
+
+impl Default for BoundaryParser {
+    fn default() -> Self {
+        Self {
+            arc_step: DEFAULT_ARC_STEP,
+        }
+    }
+}
+
+impl BoundaryParser {
+    /// Create a parser with the [default](DEFAULT_ARC_STEP) arc densification.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum angle, in degrees, between the vertices of circles and arcs.
+    ///
+    /// # Panics
+    /// Panics if the angle isn't positive.
+    pub fn with_arc_step(mut self, degrees: f64) -> Self {
+        assert!(degrees > 0f64, "arc step must be positive");
+        self.arc_step = degrees;
+        self
+    }
+
+    /// Parse lateral limits into a [`Boundary`]. Any parts of the text that describe the
+    /// boundary in words, such as along a border, are kept as unparsed fragments.
+    pub fn parse(&self, data: &str) -> Result<Boundary> {
+        let mut boundary = Boundary::default();
+        let mut pos = 0;
+        while pos < data.len() {
+            // Take the earliest of the next arc, circle or point, in that order of preference.
+            let next = [
+                ARC_RE.find_at(data, pos),
+                CIRCLE_RE.find_at(data, pos),
+                POINT_RE.find_at(data, pos),
+            ]
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, m)| m.map(|m| (i, m)))
+            .min_by_key(|(i, m)| (m.start(), *i));
+            let (i, m) = match next {
+                Some(next) => next,
+                None => break,
+            };
+            boundary.add_unparsed(&data[pos..m.start()]);
+
+            match i {
+                0 => {
+                    let caps = ARC_RE.captures(m.as_str()).unwrap();
+                    let radius = radius(&caps[2], &caps[3])?;
+                    let centre = coordinate(&caps[4])?;
+                    let end = coordinate(&caps[5])?;
+                    let clockwise = caps[1].to_lowercase() == "clockwise";
+                    match boundary.vertices.last() {
+                        Some(&start) => self.add_arc(
+                            &mut boundary.vertices,
+                            centre,
+                            radius,
+                            start,
+                            end,
+                            clockwise,
+                        ),
+                        None => boundary.unparsed.push(m.as_str().to_string()),
+                    }
+                    boundary.add_vertex(end);
+                }
+                1 => {
+                    let caps = CIRCLE_RE.captures(m.as_str()).unwrap();
+                    let radius = radius(&caps[1], &caps[2])?;
+                    let centre = coordinate(&caps[3])?;
+                    let count = (360f64 / self.arc_step).ceil() as usize;
+                    for i in 0..count {
+                        boundary.add_vertex(destination(
+                            centre,
+                            i as f64 * 360f64 / count as f64,
+                            radius,
+                        ));
+                    }
+                }
+                _ => boundary.add_vertex(coordinate(m.as_str())?),
+            }
+            pos = m.end();
+        }
+        boundary.add_unparsed(&data[pos.min(data.len())..]);
+
+        Ok(boundary)
+    }
+
+    /// Add the vertices between the start and end of an arc, excluding both. An arc that ends
+    /// where it starts is a full circle.
+    fn add_arc(
+        &self,
+        vertices: &mut Vec<(f64, f64)>,
+        centre: (f64, f64),
+        radius: f64,
+        start: (f64, f64),
+        end: (f64, f64),
+        clockwise: bool,
+    ) {
+        let from = bearing(centre, start);
+        let to = bearing(centre, end);
+        let sweep = if start == end {
+            360f64
+        } else if clockwise {
+            (to - from).rem_euclid(360f64)
+        } else {
+            (from - to).rem_euclid(360f64)
+        };
+        let sweep = if clockwise { sweep } else { -sweep };
+        let count = (sweep.abs() / self.arc_step).ceil() as usize;
+        for i in 1..count {
+            vertices.push(destination(
+                centre,
+                from + sweep * i as f64 / count as f64,
+                radius,
+            ));
+        }
+    }
+}
+
+impl Boundary {
+    /// Add a vertex, unless it repeats the last one.
+    fn add_vertex(&mut self, vertex: (f64, f64)) {
+        if self.vertices.last() != Some(&vertex) {
+            self.vertices.push(vertex);
+        }
+    }
+
+    /// Add the text between parsed parts of the limits, if it's more than separators and
+    /// joining words.
+    fn add_unparsed(&mut self, text: &str) {
+        if !NOISE_RE.replace_all(text, "").is_empty() {
+            self.unparsed
+                .push(ENDS_RE.replace_all(text, "").to_string());
+        }
+    }
+}
+
+/// Parse a radius into nautical miles.
+fn radius(value: &str, unit: &str) -> Result<f64> {
+    let value = value
+        .parse::<f64>()
+        .map_err(|_| Error::ParseError("circle radius", value.to_string()))?;
+    Ok(to_nm(value, unit))
+}
+
+/// Parse a coordinate into (latitude, longitude).
+fn coordinate(data: &str) -> Result<(f64, f64)> {
+    match parse_latlong(data)? {
+        (Some(lat), Some(lon)) => Ok((lat, lon)),
+        _ => Err(Error::ParseError("coordinate", data.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_destination() {
+        let (lat, lon) = destination((50.0, -1.0), 0f64, 60f64);
+        assert!((lat - 51.0).abs() < 0.01);
+        assert!((lon + 1.0).abs() < 1e-9);
+        let (lat, lon) = destination((0.0, 179.9), 90f64, 60f64);
+        assert!(lat.abs() < 1e-9);
+        assert!((lon + 179.1).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_bearing() {
+        assert!((bearing((50.0, -1.0), (51.0, -1.0))).abs() < 1e-9);
+        assert!((bearing((0.0, 0.0), (0.0, 1.0)) - 90f64).abs() < 1e-9);
+        assert!((bearing((51.0, -1.0), (50.0, -1.0)) - 180f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_points() {
+        let boundary = BoundaryParser::new()
+            .parse("510000N 0010000W - 520000N 0010000W - 520000N 0000000E")
+            .unwrap();
+        assert_eq!(
+            &vec![(51.0, -1.0), (52.0, -1.0), (52.0, 0.0)],
+            boundary.vertices()
+        );
+        assert!(boundary.unparsed().is_empty());
+    }
+
+    #[test]
+    fn test_parse_circle() {
+        let circle = BoundaryParser::new()
+            .parse("A circle, 2 NM radius, centred at 510000N 0010000W")
+            .unwrap();
+        assert_eq!(36, circle.vertices().len());
+        assert!((circle.vertices()[0].0 - (51.0 + 2.0 / 60.0)).abs() < 0.001);
+        assert!((circle.vertices()[0].1 + 1.0).abs() < 1e-9);
+        assert!(circle.unparsed().is_empty());
+
+        let circle = BoundaryParser::new()
+            .with_arc_step(45f64)
+            .parse("A circle of radius 3.7 KM centred on 510000N 0010000W")
+            .unwrap();
+        assert_eq!(8, circle.vertices().len());
+        assert!((circle.vertices()[4].0 - (51.0 - 2.0 / 60.0)).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_parse_arc() {
+        let text = "510000N 0000000E - 510000N 0010000E thence clockwise by the arc of a circle \
+            radius 30 NM centred on 510000N 0003000E to 510000N 0000000E";
+        let arc = BoundaryParser::new().parse(text).unwrap();
+        assert!(arc.unparsed().is_empty());
+        // The arc sweeps just over 180 degrees through the south, as the bearings from the centre
+        // aren't quite due east and west, in steps of no more than 10 degrees.
+        assert_eq!(2 + 18 + 1, arc.vertices().len());
+        assert_eq!((51.0, 0.0), *arc.vertices().last().unwrap());
+        assert!(arc.vertices()[11].0 < 50.6);
+
+        let text = text.replace("clockwise", "anticlockwise");
+        let arc = BoundaryParser::new()
+            .with_arc_step(90f64)
+            .parse(&text)
+            .unwrap();
+        assert_eq!(4, arc.vertices().len());
+        assert!(arc.vertices()[2].0 > 51.4);
+
+        // An arc back to where it started is a full circle.
+        let arc = BoundaryParser::new()
+            .parse(
+                "510000N 0010000E thence anticlockwise by the arc of a circle radius 30 NM \
+                centred on 510000N 0003000E to 510000N 0010000E",
+            )
+            .unwrap();
+        assert_eq!(1 + 35 + 1, arc.vertices().len());
+        assert_eq!(arc.vertices()[0], *arc.vertices().last().unwrap());
+        assert!(arc.vertices()[9].0 > 51.4);
+    }
+
+    #[test]
+    fn test_parse_minutes_and_seconds() {
+        let close =
+            |a: (f64, f64), b: (f64, f64)| (a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3;
+        let centre = (
+            51.0 + 30.0 / 60.0 + 12.0 / 3600.0,
+            -(12.0 / 60.0 + 34.0 / 3600.0),
+        );
+
+        let boundary = BoundaryParser::new()
+            .parse("513012N 0001234W - 522345N 0012345E")
+            .unwrap();
+        assert!(close(centre, boundary.vertices()[0]));
+        assert!(close((52.395833, 1.395833), boundary.vertices()[1]));
+
+        // The circle is centred on the corner, 2 NM (2 minutes of latitude) either side of it.
+        let boundary = BoundaryParser::new()
+            .parse("513012N 0001234W - A circle, 2 NM radius, centred at 513012N 0001234W")
+            .unwrap();
+        assert!(close(centre, boundary.vertices()[0]));
+        assert!(close(
+            (centre.0 + 2.0 / 60.0, centre.1),
+            boundary.vertices()[1]
+        ));
+        assert!(close(
+            (centre.0 - 2.0 / 60.0, centre.1),
+            boundary.vertices()[19]
+        ));
+
+        // The arc sweeps clockwise through the east, 2 NM from the centre.
+        let arc = BoundaryParser::new()
+            .with_arc_step(90f64)
+            .parse(
+                "513212N 0001234W thence clockwise by the arc of a circle radius 2 NM \
+                centred on 513012N 0001234W to 512812N 0001234W",
+            )
+            .unwrap();
+        assert_eq!(3, arc.vertices().len());
+        let east = 2.0 / 60.0 / centre.0.to_radians().cos();
+        assert!(close((centre.0, centre.1 + east), arc.vertices()[1]));
+    }
+
+    #[test]
+    fn test_parse_unparsed() {
+        let boundary = BoundaryParser::new()
+            .parse("510000N 0010000W - 520000N 0010000W - along the national border to 520000N 0000000E - 510000N 0010000W")
+            .unwrap();
+        assert_eq!(4, boundary.vertices().len());
+        assert_eq!(&vec!["along the national border"], boundary.unparsed());
+
+        let boundary = BoundaryParser::new().parse("HYDE PARK").unwrap();
+        assert!(boundary.vertices().is_empty());
+        assert_eq!(&vec!["HYDE PARK"], boundary.unparsed());
+    }
+}
@@ -20,6 +20,18 @@ pub mod airways;
 /// Parsers for airports.
 pub mod airports;
 
+/// Parsers for the list of published AIRAC cycles.
+pub mod cycles;
+
+/// Parsers for a list of ATS airspaces.
+pub mod airspaces;
+
+/// Parsers for a list of prohibited, restricted and danger areas.
+pub mod restricted_areas;
+
+/// Parsers for the lateral limits of airspaces and areas.
+pub mod geometry;
+
 /// Fetch and parse some data from an eAIP
 #[async_trait]
 pub trait FromEAIP {
@@ -33,6 +45,14 @@ pub trait FromEAIP {
     async fn from_current_eaip(eaip: &EAIP) -> Result<Self::Output> {
         Self::from_eaip(eaip, AIRAC::current()).await
     }
+
+    /// Fetch the data from the given eAIP for the next AIRAC if it has been published, otherwise
+    /// for the current AIRAC.
+    async fn from_latest_eaip(eaip: &EAIP) -> Result<CycleData<Self::Output>> {
+        let airac = eaip.latest_published_airac().await?;
+        let data = Self::from_eaip(eaip, airac.clone()).await?;
+        Ok(CycleData { airac, data })
+    }
 }
 
 /// The trait for all eAIP data parsers
@@ -75,10 +95,8 @@ fn get_clean_text_traverse(traverse: Traverse<'_, Node>) -> String {
                             }
                         }
                     }
-                    Node::Text(t) => {
-                        if ignore_chain.is_empty() {
-                            s += &*t.trim();
-                        }
+                    Node::Text(t) if ignore_chain.is_empty() => {
+                        s += t.trim();
                     }
                     _ => (),
                 }
@@ -87,7 +105,7 @@ fn get_clean_text_traverse(traverse: Traverse<'_, Node>) -> String {
                 // if matches end of ignore chain, pop_front
                 if let Node::Element(e) = node.value() {
                     if !s.ends_with('\n') {
-                        let inline_elems = vec!["span", "strong", "i", "em"];
+                        let inline_elems = ["span", "strong", "i", "em"];
                         if !inline_elems.contains(&&*e.name().to_lowercase()) {
                             s += "\n";
                         }
@@ -105,14 +123,15 @@ fn get_clean_text_traverse(traverse: Traverse<'_, Node>) -> String {
 
 /// Parses a frequency - always returns kHz
 pub(crate) fn parse_frequency<S: Into<String>>(data: S) -> Result<usize> {
-    let re = Regex::new(r"([0-9.]{3,7})\s*([kM])Hz").unwrap();
+    let re = Regex::new(r"([0-9.]{2,7})\s*([kM])Hz").unwrap();
     let ch_re = Regex::new(r"CH(\d+[XY])").unwrap();
     let data = data.into();
 
     if let Some(caps) = re.captures(&data) {
-        let mut freq = caps[1].parse::<f32>().unwrap();
+        let mut freq = caps[1].parse::<f64>().unwrap();
         if &caps[2] == "M" {
-            freq *= 1000f32;
+            // Round, as MHz frequencies aren't exact in binary
+            freq = (freq * 1000f64).round();
         }
         Ok(freq as usize)
     } else if let Some(caps) = ch_re.captures(&data) {
@@ -146,22 +165,38 @@ pub(crate) fn parse_frequency<S: Into<String>>(data: S) -> Result<usize> {
     }
 }
 
-/// Parses a latlong
+/// Converts a packed `DDMMSS.ss` or `DDDMMSS.ss` coordinate into decimal degrees. Values with
+/// fewer than five whole digits are already in degrees.
+fn dms_to_decimal(raw: &str) -> f64 {
+    let (whole, _) = raw.split_once('.').unwrap_or((raw, ""));
+    if whole.len() < 5 {
+        return raw.parse::<f64>().unwrap();
+    }
+    let split = whole.len() - 4;
+    let degrees = raw[..split].parse::<f64>().unwrap();
+    let minutes = raw[split..split + 2].parse::<f64>().unwrap();
+    let seconds = raw[split + 2..].parse::<f64>().unwrap();
+    degrees + (minutes / 60f64) + (seconds / 3600f64)
+}
+
+/// Parses a latlong into decimal degrees
 pub(crate) fn parse_latlong<S: Into<String>>(data: S) -> Result<(Option<f64>, Option<f64>)> {
     let re = Regex::new(r"(?:([0-9.]{6,})([NnSs]))?\s*(?:([0-9.]{7,})([EeWw]))?").unwrap();
-    let dms_re = Regex::new(r#"(?:(\d+)°(\d+)'([\d.]+)"([NnSs]))?\s*(?:(\d+)°(\d+)'([\d.]+)"([EeWw]))?"#).unwrap();
+    let dms_re =
+        Regex::new(r#"(?:(\d+)°(\d+)'([\d.]+)"([NnSs]))?\s*(?:(\d+)°(\d+)'([\d.]+)"([EeWw]))?"#)
+            .unwrap();
     let data = data.into();
     let mut lat = None;
     let mut lon = None;
     if let Some(caps) = re.captures(&data) {
         if let Some(raw_lat) = caps.get(1) {
-            lat = Some(raw_lat.as_str().parse::<f64>().unwrap() / 10000f64);
+            lat = Some(dms_to_decimal(raw_lat.as_str()));
             if caps[2].to_lowercase() == *"s" {
                 lat = Some(-lat.unwrap());
             }
         }
         if let Some(raw_lon) = caps.get(3) {
-            lon = Some(raw_lon.as_str().parse::<f64>().unwrap() / 10000f64);
+            lon = Some(dms_to_decimal(raw_lon.as_str()));
             if caps[4].to_lowercase() == *"w" {
                 lon = Some(-lon.unwrap());
             }
@@ -191,20 +226,27 @@ pub(crate) fn parse_latlong<S: Into<String>>(data: S) -> Result<(Option<f64>, Op
         }
     }
 
-    if lat == None && lon == None {
+    if lat.is_none() && lon.is_none() {
         Err(Error::ParseError("latlong", data))
     } else {
         Ok((lat, lon))
     }
 }
 
+/// Finds a vertical limit, such as `FL 195`, `3500 FT ALT`, `UNL` or `SFC`, in a line of text.
+pub(crate) fn parse_vertical_limit(data: &str) -> Option<String> {
+    let re = Regex::new(r"(FL\s*\d+|\d+\s*(?:FT|ft)(?:\s*(?:ALT|AMSL|MSL|AGL|SFC))?|UNL|SFC|GND)")
+        .unwrap();
+    re.captures(data).map(|caps| caps[1].to_string())
+}
+
 /// Parses an elevation, always returning ft
-pub(crate) fn parse_elevation<S: Into<String>>(data: S) -> Result<usize> {
-    let re = Regex::new(r"([0-9]+)\s*(?:ft|FT)").unwrap();
+pub(crate) fn parse_elevation<S: Into<String>>(data: S) -> Result<isize> {
+    let re = Regex::new(r"(-?[0-9]+)\s*(?:ft|FT)").unwrap();
     let data = data.into();
 
     if let Some(caps) = re.captures(&data) {
-        Ok(caps[1].parse::<usize>().unwrap())
+        Ok(caps[1].parse::<isize>().unwrap())
     } else {
         Err(Error::ParseError("elevation", data))
     }
@@ -212,7 +254,9 @@ pub(crate) fn parse_elevation<S: Into<String>>(data: S) -> Result<usize> {
 
 #[cfg(test)]
 mod tests {
-    use super::{get_clean_text, parse_elevation, parse_frequency, parse_latlong};
+    use super::{
+        get_clean_text, parse_elevation, parse_frequency, parse_latlong, parse_vertical_limit,
+    };
 
     #[test]
     fn test_already_clean_string() {
@@ -251,6 +295,8 @@ mod tests {
         assert_eq!(123456, parse_frequency("123.456 MHz").unwrap());
         assert_eq!(123456, parse_frequency("123456 kHz").unwrap());
         assert_eq!(123000, parse_frequency("123MHz").unwrap());
+        assert_eq!(128305, parse_frequency("128.305 MHz").unwrap());
+        assert_eq!(75000, parse_frequency("75 MHz").unwrap());
     }
 
     #[test]
@@ -258,66 +304,69 @@ mod tests {
         assert_eq!(10, parse_elevation("10ft").unwrap());
         assert_eq!(10, parse_elevation("10 ft").unwrap());
         assert_eq!(10, parse_elevation("10 FT").unwrap());
+        assert_eq!(-11, parse_elevation("-11 FT").unwrap());
         assert!(parse_elevation("10M").is_err());
     }
 
     #[test]
-    fn test_parse_latlong() {
-        assert_eq!(
-            (Some(57.1209), Some(2.1153)),
-            parse_latlong("571209N 0021153E").unwrap()
-        );
-        assert_eq!(
-            (Some(57.1209), Some(-2.1153)),
-            parse_latlong("571209N 0021153W").unwrap()
-        );
-        assert_eq!(
-            (Some(-57.1209), Some(2.1153)),
-            parse_latlong("571209S 0021153E").unwrap()
-        );
+    fn test_parse_vertical_limit() {
         assert_eq!(
-            (Some(-57.1209), Some(-2.1153)),
-            parse_latlong("571209S 0021153W").unwrap()
+            Some("FL 195".to_string()),
+            parse_vertical_limit("Upper limit: FL 195")
         );
-
         assert_eq!(
-            (Some(57.1209), Some(2.1153)),
-            parse_latlong("571209n 0021153e").unwrap()
+            Some("3500 FT ALT".to_string()),
+            parse_vertical_limit("3500 FT ALT")
         );
-        assert_eq!(
-            (Some(57.1209), Some(-2.1153)),
-            parse_latlong("571209n 0021153w").unwrap()
-        );
-        assert_eq!(
-            (Some(-57.1209), Some(2.1153)),
-            parse_latlong("571209s 0021153e").unwrap()
-        );
-        assert_eq!(
-            (Some(-57.1209), Some(-2.1153)),
-            parse_latlong("571209s 0021153w").unwrap()
-        );
-
-        assert_eq!((Some(57.1209), None), parse_latlong("571209N").unwrap());
-        assert_eq!((Some(-57.1209), None), parse_latlong("571209S").unwrap());
-        assert_eq!((None, Some(2.1153)), parse_latlong("0021153E").unwrap());
-        assert_eq!((None, Some(-2.1153)), parse_latlong("0021153W").unwrap());
+        assert_eq!(Some("SFC".to_string()), parse_vertical_limit("SFC"));
+        assert_eq!(None, parse_vertical_limit("H24"));
+    }
 
-        assert_eq!(
-            (Some(57.120962), None),
-            parse_latlong("571209.62N").unwrap()
-        );
-        assert_eq!(
-            (None, Some(2.115312)),
-            parse_latlong("0021153.12E").unwrap()
+    /// Asserts that a parsed latlong matches the expected decimal degrees
+    fn assert_latlong(expected: (Option<f64>, Option<f64>), data: &str) {
+        let actual = parse_latlong(data).unwrap();
+        let close = |a: Option<f64>, b: Option<f64>| match (a, b) {
+            (Some(a), Some(b)) => (a - b).abs() < 1e-9,
+            (a, b) => a == b,
+        };
+        assert!(
+            close(expected.0, actual.0) && close(expected.1, actual.1),
+            "{data}: expected {expected:?}, got {actual:?}"
         );
+    }
 
-        assert_eq!(
-            (Some(57.12096), None),
-            parse_latlong("571209.6N").unwrap()
+    #[test]
+    fn test_parse_latlong() {
+        assert_latlong((Some(57.2025), Some(2.198055555)), "571209N 0021153E");
+        assert_latlong((Some(57.2025), Some(-2.198055555)), "571209N 0021153W");
+        assert_latlong((Some(-57.2025), Some(2.198055555)), "571209S 0021153E");
+        assert_latlong((Some(-57.2025), Some(-2.198055555)), "571209S 0021153W");
+
+        assert_latlong((Some(57.2025), Some(2.198055555)), "571209n 0021153e");
+        assert_latlong((Some(57.2025), Some(-2.198055555)), "571209n 0021153w");
+        assert_latlong((Some(-57.2025), Some(2.198055555)), "571209s 0021153e");
+        assert_latlong((Some(-57.2025), Some(-2.198055555)), "571209s 0021153w");
+
+        assert_latlong((Some(57.2025), None), "571209N");
+        assert_latlong((Some(-57.2025), None), "571209S");
+        assert_latlong((None, Some(2.198055555)), "0021153E");
+        assert_latlong((None, Some(-2.198055555)), "0021153W");
+
+        assert_latlong((Some(57.202672222), None), "571209.62N");
+        assert_latlong((None, Some(2.198088888)), "0021153.12E");
+
+        assert_latlong((Some(57.202666666), None), "571209.6N");
+        assert_latlong((None, Some(2.198083333)), "0021153.1E");
+
+        // Minutes and seconds are sexagesimal, not decimal fractions of a degree.
+        assert_latlong(
+            (Some(57.310833333), Some(-2.267222222)),
+            "571839.00N 0021602.00W",
         );
-        assert_eq!(
-            (None, Some(2.11531)),
-            parse_latlong("0021153.1E").unwrap()
+        assert_latlong((Some(51.503333333), Some(-0.209444444)), "513012N 0001234W");
+        assert_latlong(
+            (Some(12.999722222), Some(179.999722222)),
+            "125959N 1795959E",
         );
 
         if let Some(v) = parse_latlong(r#"50°50'13.60"N"#).unwrap().0 {
@@ -0,0 +1,131 @@
+use async_trait::async_trait;
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+
+use crate::parse::geometry::BoundaryParser;
+use crate::parse::{get_clean_text, parse_vertical_limit};
+use crate::prelude::*;
+
+/// A list of prohibited, restricted and danger areas that can be parsed with a [`Parser`] from
+/// data from an [`EAIP`](crate::eaip::EAIP).
+pub type RestrictedAreas = Vec<RestrictedArea>;
+
+#[async_trait]
+impl FromEAIP for RestrictedAreas {
+    type Output = Self;
+
+    async fn from_eaip(eaip: &EAIP, airac: airac::AIRAC) -> Result<Self::Output> {
+        let page = Part::EnRoute(ENR::NavWarnings(1));
+        let data = eaip.get_page(airac, page, EAIPType::HTML).await?;
+        let areas = RestrictedAreas::parse(&data)?;
+        Ok(areas)
+    }
+}
+
+impl<'a> Parser<'a> for RestrictedAreas {
+    type Output = Self;
+
+    fn parse(data: &'a str) -> Result<Self::Output> {
+        let html = Html::parse_document(data);
+        let row_selector = Selector::parse("table > tbody > tr").unwrap();
+        let upper_selector = Selector::parse("td.Upper").unwrap();
+        let lower_selector = Selector::parse("td.Lower").unwrap();
+        let designator_re = Regex::new(r"^([A-Z]{2})\s?([PRD])\s?-?\s?(\d+[A-Z]?)\b(.*)").unwrap();
+        let activity_re = Regex::new(
+            r"(?i)^(?:activity|times? of activity|hours? of activity|active)\s*:?\s*(.+)",
+        )
+        .unwrap();
+        let boundary_parser = BoundaryParser::new();
+
+        let mut areas = Vec::new();
+        for row in html.select(&row_selector) {
+            // Skip the rows of tables nested within cells, such as vertical limits.
+            if row
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(|e| e.value().name() == "td")
+            {
+                continue;
+            }
+            let cells = row
+                .children()
+                .filter_map(ElementRef::wrap)
+                .filter(|e| e.value().name() == "td")
+                .collect::<Vec<_>>();
+            if cells.is_empty() {
+                continue;
+            }
+
+            // Column 1 starts with the designator, followed by the name and lateral limits
+            let first = get_clean_text(cells[0].inner_html());
+            let lines = first
+                .lines()
+                .map(|l| l.trim())
+                .filter(|l| !l.is_empty())
+                .collect::<Vec<_>>();
+            let caps = match lines.first().and_then(|l| designator_re.captures(l)) {
+                Some(caps) => caps,
+                None => continue,
+            };
+            let mut area = RestrictedArea {
+                designator: format!("{} {}{}", &caps[1], &caps[2], &caps[3]),
+                kind: match &caps[2] {
+                    "P" => RestrictedAreaKind::Prohibited,
+                    "R" => RestrictedAreaKind::Restricted,
+                    _ => RestrictedAreaKind::Danger,
+                },
+                name: caps[4]
+                    .trim_matches(|c: char| c.is_whitespace() || c == '-' || c == ':')
+                    .to_string(),
+                ..Default::default()
+            };
+            let mut lateral = &lines[1..];
+            if area.name.is_empty() {
+                if let Some(name) = lateral.first().filter(
+                    |l| matches!(boundary_parser.parse(l), Ok(b) if b.vertices().is_empty()),
+                ) {
+                    area.name = name.to_string();
+                    lateral = &lateral[1..];
+                }
+            }
+            area.lateral_limits = lateral.join(" ");
+            let boundary = boundary_parser.parse(&area.lateral_limits)?;
+            area.boundary = boundary.vertices;
+            area.unparsed_limits = boundary.unparsed;
+
+            // Column 2 contains the vertical limits, as a small table or as text
+            if let Some(cell) = cells.get(1) {
+                if let Some(upper) = cell.select(&upper_selector).next() {
+                    area.upper_limit = get_clean_text(upper.inner_html());
+                }
+                if let Some(lower) = cell.select(&lower_selector).next() {
+                    area.lower_limit = get_clean_text(lower.inner_html());
+                }
+                if area.upper_limit.is_empty() && area.lower_limit.is_empty() {
+                    let text = get_clean_text(cell.inner_html());
+                    let mut limits = text.lines().filter_map(parse_vertical_limit);
+                    area.upper_limit = limits.next().unwrap_or_default();
+                    area.lower_limit = limits.next().unwrap_or_default();
+                }
+            }
+
+            // Column 3 contains remarks, including the times of activity
+            if let Some(cell) = cells.get(2) {
+                let remarks = get_clean_text(cell.inner_html());
+                let remarks = remarks
+                    .lines()
+                    .map(|l| l.trim())
+                    .filter(|l| !l.is_empty())
+                    .collect::<Vec<_>>();
+                if let Some(caps) = remarks.iter().find_map(|l| activity_re.captures(l)) {
+                    area.activity = caps[1].trim().to_string();
+                }
+                area.remarks = remarks.join("\n");
+            }
+
+            areas.push(area);
+        }
+
+        Ok(areas)
+    }
+}
@@ -3,6 +3,7 @@ use std::fmt::Display;
 
 /// The type of file to get from the eAIP
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EAIPType {
     /// An HTML file
     HTML,
@@ -25,6 +26,7 @@ impl Display for EAIPType {
 
 /// The parts of an AIP.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Part {
     /// General (GEN)
     General(GEN),
@@ -49,6 +51,7 @@ impl Display for Part {
 
 /// The General parts of an AIP
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GEN {
     /// GEN 0
     Overview(usize),
@@ -76,6 +79,7 @@ impl Display for GEN {
 
 /// The En-Route parts of an AIP
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ENR {
     /// ENR 0
     TableOfContents,
@@ -109,6 +113,7 @@ impl Display for ENR {
 
 /// The Aerodromes parts of an AIP
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AD {
     /// AD 0
     TableOfContents,
@@ -303,7 +308,7 @@ mod tests {
         assert_eq!(
             "/2022-05-19-AIRAC/html/eAIP/EG-AD-2.EGBO-en-GB.html",
             generate_location_with_airac(
-                airac::AIRAC::from_ymd(2022, 05, 19),
+                airac::AIRAC::from_ymd(2022, 5, 19),
                 "EG",
                 Part::Aerodromes(AD::Aerodromes("EGBO".to_string())),
                 "en-GB",
@@ -1,8 +1,10 @@
 use crate::prelude::*;
-use airac::AIRAC;
+use airac::{NaiveDate, AIRAC};
+use std::fmt::Display;
 
 /// A radio-based navigational aid.
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NavAid {
     pub(crate) id: String,
     pub(crate) name: String,
@@ -10,7 +12,11 @@ pub struct NavAid {
     pub(crate) frequency_khz: usize,
     pub(crate) latitude: f64,
     pub(crate) longitude: f64,
-    pub(crate) elevation: usize,
+    pub(crate) elevation: isize,
+    pub(crate) channel: Option<String>,
+    pub(crate) runway: Option<String>,
+    pub(crate) course: Option<f64>,
+    pub(crate) glidepath_angle: Option<f64>,
 }
 
 impl NavAid {
@@ -39,27 +45,49 @@ impl NavAid {
         self.frequency_khz as f32 / 1000f32
     }
 
-    /// The latitude of this navaid.
+    /// The latitude of this navaid, in decimal degrees.
     pub fn latitude(&self) -> f64 {
         self.latitude
     }
 
-    /// The longitude of this navaid.
+    /// The longitude of this navaid, in decimal degrees.
     pub fn longitude(&self) -> f64 {
         self.longitude
     }
 
-    /// The elevation of this navaid. Note that for NDBs, elevation isn't always given
-    /// as it has little effect on the use of the navaid.
-    pub fn elevation(&self) -> usize {
+    /// The elevation of this navaid, in feet, which is negative below sea level. Note that for
+    /// NDBs, elevation isn't always given as it has little effect on the use of the navaid.
+    pub fn elevation(&self) -> isize {
         self.elevation
     }
+
+    /// The DME channel of this navaid, such as `CH36X`, if given.
+    pub fn channel(&self) -> Option<&String> {
+        self.channel.as_ref()
+    }
+
+    /// The designator of the runway an aerodrome navaid serves, such as `16`.
+    pub fn runway(&self) -> Option<&String> {
+        self.runway.as_ref()
+    }
+
+    /// The course of a localiser, in degrees, if given.
+    pub fn course(&self) -> Option<f64> {
+        self.course
+    }
+
+    /// The angle of a glidepath, in degrees, if given.
+    pub fn glidepath_angle(&self) -> Option<f64> {
+        self.glidepath_angle
+    }
 }
 
 /// The kind of navaid this [`NavAid`] is.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NavAidKind {
     /// A VOR (VHF omnidirectional range) navaid
+    #[default]
     VOR,
     /// A DME (distance measuring equipment) only navaid
     DME,
@@ -69,16 +97,34 @@ pub enum NavAidKind {
     NDB,
     /// A TACAN navaid, usually for military use
     TACAN,
+    /// The localiser of an ILS, or a localiser on its own
+    LOC,
+    /// The glidepath of an ILS
+    GP,
+    /// An outer marker beacon
+    OM,
+    /// A middle marker beacon
+    MM,
+    /// An inner marker beacon
+    IM,
+    /// A locator, which is a low powered NDB used for approaches to an aerodrome
+    Locator,
 }
 
-impl Default for NavAidKind {
-    fn default() -> Self {
-        Self::VOR
+impl NavAidKind {
+    /// Is this a kind of navaid that is only listed for an aerodrome, in AD 2.19, rather than
+    /// with the en-route navaids?
+    pub fn is_aerodrome(&self) -> bool {
+        matches!(
+            self,
+            Self::LOC | Self::GP | Self::OM | Self::MM | Self::IM | Self::Locator
+        )
     }
 }
 
 /// An intersection (navigational waypoint).
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Intersection {
     pub(crate) designator: String,
     pub(crate) latitude: f64,
@@ -91,12 +137,12 @@ impl Intersection {
         &self.designator
     }
 
-    /// The latitude of the intersection.
+    /// The latitude of the intersection, in decimal degrees.
     pub fn latitude(&self) -> f64 {
         self.latitude
     }
 
-    /// The longitude of the intersection.
+    /// The longitude of the intersection, in decimal degrees.
     pub fn longitude(&self) -> f64 {
         self.longitude
     }
@@ -104,6 +150,7 @@ impl Intersection {
 
 /// An airway.
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Airway {
     pub(crate) designator: String,
     pub(crate) waypoints: Vec<AirwayWaypoint>,
@@ -123,6 +170,7 @@ impl Airway {
 
 /// A waypoint on an airway.
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AirwayWaypoint {
     pub(crate) designator: String,
     pub(crate) lower_limit: String,
@@ -159,12 +207,22 @@ impl AirwayWaypoint {
 
 /// Data about an airport
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Airport {
     pub(crate) icao: String,
     pub(crate) name: String,
     pub(crate) latitude: f64,
     pub(crate) longitude: f64,
-    pub(crate) elevation: usize,
+    pub(crate) elevation: isize,
+    pub(crate) runways: Vec<Runway>,
+    pub(crate) declared_distances: Vec<DeclaredDistances>,
+    pub(crate) communications: Vec<CommunicationService>,
+    pub(crate) operational_hours: OperationalHours,
+    pub(crate) handling_services: HandlingServices,
+    pub(crate) passenger_facilities: PassengerFacilities,
+    pub(crate) rescue_firefighting: RescueFirefighting,
+    pub(crate) seasonal_availability: SeasonalAvailability,
+    pub(crate) navaids: Vec<NavAid>,
     pub(crate) charts: Vec<Chart>,
 }
 
@@ -172,7 +230,9 @@ impl Airport {
     /// Fetch the data from the given eAIP for the given AIRAC.
     pub async fn from_eaip(eaip: &EAIP, airac: AIRAC, aerodrome: String) -> Result<Self> {
         let egbo = Part::Aerodromes(AD::Aerodromes(aerodrome));
-        let data = eaip.get_current_page(egbo.clone(), EAIPType::HTML).await?;
+        let data = eaip
+            .get_page(airac.clone(), egbo.clone(), EAIPType::HTML)
+            .await?;
         let mut airport = Airport::parse(&data)?;
         airport.canonicalise_chart_urls(eaip, airac, egbo)?;
         Ok(airport)
@@ -183,6 +243,14 @@ impl Airport {
         Self::from_eaip(eaip, AIRAC::current(), aerodrome).await
     }
 
+    /// Fetch the data from the given eAIP for the next AIRAC if it has been published, otherwise
+    /// for the current AIRAC.
+    pub async fn from_latest_eaip(eaip: &EAIP, aerodrome: String) -> Result<CycleData<Self>> {
+        let airac = eaip.latest_published_airac().await?;
+        let data = Self::from_eaip(eaip, airac.clone(), aerodrome).await?;
+        Ok(CycleData { airac, data })
+    }
+
     /// Canonicalise chart URLs so they aren't relative.
     pub fn canonicalise_chart_urls(&mut self, eaip: &EAIP, airac: AIRAC, part: Part) -> Result<()> {
         let base_url = url::Url::parse(&eaip.generate_url(airac, part, EAIPType::HTML));
@@ -212,29 +280,591 @@ impl Airport {
         &self.name
     }
 
-    /// The aerodrome's latitude
+    /// The aerodrome's latitude, in decimal degrees
     pub fn latitude(&self) -> f64 {
         self.latitude
     }
 
-    /// The aerodrome's longitude
+    /// The aerodrome's longitude, in decimal degrees
     pub fn longitude(&self) -> f64 {
         self.longitude
     }
 
-    /// The aerodrome's elevation
-    pub fn elevation(&self) -> usize {
+    /// The aerodrome's elevation, in feet, which is negative below sea level
+    pub fn elevation(&self) -> isize {
         self.elevation
     }
 
+    /// The operational hours of the aerodrome and its services, from AD 2.3
+    pub fn operational_hours(&self) -> &OperationalHours {
+        &self.operational_hours
+    }
+
+    /// The handling services and facilities at the aerodrome, from AD 2.4
+    pub fn handling_services(&self) -> &HandlingServices {
+        &self.handling_services
+    }
+
+    /// The passenger facilities at the aerodrome, from AD 2.5
+    pub fn passenger_facilities(&self) -> &PassengerFacilities {
+        &self.passenger_facilities
+    }
+
+    /// The rescue and firefighting services at the aerodrome, from AD 2.6
+    pub fn rescue_firefighting(&self) -> &RescueFirefighting {
+        &self.rescue_firefighting
+    }
+
+    /// The seasonal availability of the aerodrome, from AD 2.7
+    pub fn seasonal_availability(&self) -> &SeasonalAvailability {
+        &self.seasonal_availability
+    }
+
+    /// The runways at the aerodrome, one for each direction
+    pub fn runways(&self) -> &Vec<Runway> {
+        &self.runways
+    }
+
+    /// The declared distances for each runway direction, including intersection departures
+    pub fn declared_distances(&self) -> &Vec<DeclaredDistances> {
+        &self.declared_distances
+    }
+
+    /// The declared distances for a runway direction, such as `16`
+    pub fn declared_distances_for(&self, runway: &str) -> Vec<&DeclaredDistances> {
+        self.declared_distances
+            .iter()
+            .filter(|d| d.runway == runway)
+            .collect()
+    }
+
+    /// The ATS communication facilities at the aerodrome, such as tower and ATIS
+    pub fn communications(&self) -> &Vec<CommunicationService> {
+        &self.communications
+    }
+
+    /// The ATS communication facilities of a kind at the aerodrome
+    pub fn communications_of(&self, kind: CommunicationServiceKind) -> Vec<&CommunicationService> {
+        self.communications
+            .iter()
+            .filter(|c| c.kind == kind)
+            .collect()
+    }
+
+    /// The radio navigation and landing aids at the aerodrome, such as ILS localisers and
+    /// glidepaths
+    pub fn navaids(&self) -> &Vec<NavAid> {
+        &self.navaids
+    }
+
     /// Charts relating to the aerodrome
     pub fn charts(&self) -> &Vec<Chart> {
         &self.charts
     }
 }
 
+/// The operational hours of an aerodrome and its services, from AD 2.3. Each is as written in
+/// the eAIP, such as `H24`, or empty if not given.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperationalHours {
+    pub(crate) operator: String,
+    pub(crate) customs: String,
+    pub(crate) health: String,
+    pub(crate) ais_briefing: String,
+    pub(crate) ats_reporting: String,
+    pub(crate) met_briefing: String,
+    pub(crate) ats: String,
+    pub(crate) fuelling: String,
+    pub(crate) handling: String,
+    pub(crate) security: String,
+    pub(crate) de_icing: String,
+    pub(crate) remarks: String,
+    pub(crate) has_customs: bool,
+    pub(crate) has_ais_briefing: bool,
+}
+
+impl OperationalHours {
+    /// The hours of the aerodrome operator
+    pub fn operator(&self) -> &String {
+        &self.operator
+    }
+
+    /// The hours of customs and immigration
+    pub fn customs(&self) -> &String {
+        &self.customs
+    }
+
+    /// The hours of health and sanitation
+    pub fn health(&self) -> &String {
+        &self.health
+    }
+
+    /// The hours of the AIS briefing office
+    pub fn ais_briefing(&self) -> &String {
+        &self.ais_briefing
+    }
+
+    /// The hours of the ATS reporting office (ARO)
+    pub fn ats_reporting(&self) -> &String {
+        &self.ats_reporting
+    }
+
+    /// The hours of the MET briefing office
+    pub fn met_briefing(&self) -> &String {
+        &self.met_briefing
+    }
+
+    /// The hours of air traffic services
+    pub fn ats(&self) -> &String {
+        &self.ats
+    }
+
+    /// The hours of fuelling
+    pub fn fuelling(&self) -> &String {
+        &self.fuelling
+    }
+
+    /// The hours of handling
+    pub fn handling(&self) -> &String {
+        &self.handling
+    }
+
+    /// The hours of security
+    pub fn security(&self) -> &String {
+        &self.security
+    }
+
+    /// The hours of de-icing
+    pub fn de_icing(&self) -> &String {
+        &self.de_icing
+    }
+
+    /// Any remarks
+    pub fn remarks(&self) -> &String {
+        &self.remarks
+    }
+
+    /// Are customs and immigration available at any time?
+    pub fn has_customs(&self) -> bool {
+        self.has_customs
+    }
+
+    /// Is an AIS briefing office available at any time?
+    pub fn has_ais_briefing(&self) -> bool {
+        self.has_ais_briefing
+    }
+}
+
+/// The handling services and facilities at an aerodrome, from AD 2.4. Each is as written in the
+/// eAIP, or empty if not given.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandlingServices {
+    pub(crate) cargo: String,
+    pub(crate) fuel: String,
+    pub(crate) fuelling: String,
+    pub(crate) de_icing: String,
+    pub(crate) hangar_space: String,
+    pub(crate) repair: String,
+    pub(crate) remarks: String,
+    pub(crate) fuel_types: Vec<String>,
+    pub(crate) has_de_icing: bool,
+}
+
+impl HandlingServices {
+    /// The cargo handling facilities
+    pub fn cargo(&self) -> &String {
+        &self.cargo
+    }
+
+    /// The fuel and oil types
+    pub fn fuel(&self) -> &String {
+        &self.fuel
+    }
+
+    /// The fuelling facilities and capacity
+    pub fn fuelling(&self) -> &String {
+        &self.fuelling
+    }
+
+    /// The de-icing facilities
+    pub fn de_icing(&self) -> &String {
+        &self.de_icing
+    }
+
+    /// The hangar space for visiting aircraft
+    pub fn hangar_space(&self) -> &String {
+        &self.hangar_space
+    }
+
+    /// The repair facilities for visiting aircraft
+    pub fn repair(&self) -> &String {
+        &self.repair
+    }
+
+    /// Any remarks
+    pub fn remarks(&self) -> &String {
+        &self.remarks
+    }
+
+    /// The types of fuel available, such as `AVGAS 100LL` and `JET A-1`.
+    pub fn fuel_types(&self) -> &Vec<String> {
+        &self.fuel_types
+    }
+
+    /// Are de-icing facilities available?
+    pub fn has_de_icing(&self) -> bool {
+        self.has_de_icing
+    }
+}
+
+/// The passenger facilities at and near an aerodrome, from AD 2.5. Each is as written in the
+/// eAIP, or empty if not given.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PassengerFacilities {
+    pub(crate) hotels: String,
+    pub(crate) restaurants: String,
+    pub(crate) transportation: String,
+    pub(crate) medical: String,
+    pub(crate) bank_and_post_office: String,
+    pub(crate) tourist_office: String,
+    pub(crate) remarks: String,
+}
+
+impl PassengerFacilities {
+    /// The hotels
+    pub fn hotels(&self) -> &String {
+        &self.hotels
+    }
+
+    /// The restaurants
+    pub fn restaurants(&self) -> &String {
+        &self.restaurants
+    }
+
+    /// The transportation
+    pub fn transportation(&self) -> &String {
+        &self.transportation
+    }
+
+    /// The medical facilities
+    pub fn medical(&self) -> &String {
+        &self.medical
+    }
+
+    /// The bank and post office
+    pub fn bank_and_post_office(&self) -> &String {
+        &self.bank_and_post_office
+    }
+
+    /// The tourist office
+    pub fn tourist_office(&self) -> &String {
+        &self.tourist_office
+    }
+
+    /// Any remarks
+    pub fn remarks(&self) -> &String {
+        &self.remarks
+    }
+}
+
+/// The rescue and firefighting (RFF) services at an aerodrome, from AD 2.6. Each is as written
+/// in the eAIP, or empty if not given.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RescueFirefighting {
+    pub(crate) category: String,
+    pub(crate) rescue_equipment: String,
+    pub(crate) removal: String,
+    pub(crate) remarks: String,
+    pub(crate) category_number: Option<u8>,
+}
+
+impl RescueFirefighting {
+    /// The aerodrome category for firefighting, as written, such as `RFF Category 7`
+    pub fn category(&self) -> &String {
+        &self.category
+    }
+
+    /// The rescue equipment
+    pub fn rescue_equipment(&self) -> &String {
+        &self.rescue_equipment
+    }
+
+    /// The capability for removal of disabled aircraft
+    pub fn removal(&self) -> &String {
+        &self.removal
+    }
+
+    /// Any remarks
+    pub fn remarks(&self) -> &String {
+        &self.remarks
+    }
+
+    /// The aerodrome category for firefighting as a number, from 1 to 10, if given.
+    pub fn category_number(&self) -> Option<u8> {
+        self.category_number
+    }
+}
+
+/// The seasonal availability of an aerodrome, and its clearing, from AD 2.7. Each is as written
+/// in the eAIP, or empty if not given.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeasonalAvailability {
+    pub(crate) clearing_equipment: String,
+    pub(crate) clearance_priorities: String,
+    pub(crate) remarks: String,
+    pub(crate) has_clearing: bool,
+}
+
+impl SeasonalAvailability {
+    /// The types of clearing equipment
+    pub fn clearing_equipment(&self) -> &String {
+        &self.clearing_equipment
+    }
+
+    /// The clearance priorities
+    pub fn clearance_priorities(&self) -> &String {
+        &self.clearance_priorities
+    }
+
+    /// Any remarks
+    pub fn remarks(&self) -> &String {
+        &self.remarks
+    }
+
+    /// Is there clearing equipment, such as for snow?
+    pub fn has_clearing(&self) -> bool {
+        self.has_clearing
+    }
+}
+
+/// The physical characteristics of a runway, in one direction, from AD 2.12.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Runway {
+    pub(crate) designator: String,
+    pub(crate) true_bearing: f64,
+    pub(crate) magnetic_bearing: Option<f64>,
+    pub(crate) length: usize,
+    pub(crate) width: usize,
+    pub(crate) surface: String,
+    pub(crate) strength: String,
+    pub(crate) threshold_latitude: f64,
+    pub(crate) threshold_longitude: f64,
+    pub(crate) threshold_elevation: f64,
+    pub(crate) tdz_elevation: Option<f64>,
+}
+
+impl Runway {
+    /// The designator of the runway, such as `16` or `09L`
+    pub fn designator(&self) -> &String {
+        &self.designator
+    }
+
+    /// The true bearing of the runway, in degrees
+    pub fn true_bearing(&self) -> f64 {
+        self.true_bearing
+    }
+
+    /// The magnetic bearing of the runway, in degrees, if given
+    pub fn magnetic_bearing(&self) -> Option<f64> {
+        self.magnetic_bearing
+    }
+
+    /// The length of the runway, in metres
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// The width of the runway, in metres
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The surface of the runway, such as `Asphalt`
+    pub fn surface(&self) -> &String {
+        &self.surface
+    }
+
+    /// The strength of the runway, as a PCN such as `PCN 49/F/B/W/T`
+    pub fn strength(&self) -> &String {
+        &self.strength
+    }
+
+    /// The latitude of the threshold, in decimal degrees
+    pub fn threshold_latitude(&self) -> f64 {
+        self.threshold_latitude
+    }
+
+    /// The longitude of the threshold, in decimal degrees
+    pub fn threshold_longitude(&self) -> f64 {
+        self.threshold_longitude
+    }
+
+    /// The elevation of the threshold, in feet, which is negative below sea level
+    pub fn threshold_elevation(&self) -> f64 {
+        self.threshold_elevation
+    }
+
+    /// The highest elevation of the touchdown zone, in feet, if given for a precision approach
+    /// runway
+    pub fn tdz_elevation(&self) -> Option<f64> {
+        self.tdz_elevation
+    }
+}
+
+/// A distance in metres.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Metres(pub usize);
+
+impl Metres {
+    /// The distance in feet, rounded to the nearest foot.
+    pub fn feet(&self) -> usize {
+        (self.0 as f64 / 0.3048).round() as usize
+    }
+}
+
+impl Display for Metres {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} m", self.0)
+    }
+}
+
+/// The declared distances for a runway direction, from AD 2.13. A distance is `None` where it is
+/// not usable or not given, such as the LDA for an intersection departure.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeclaredDistances {
+    pub(crate) runway: String,
+    pub(crate) intersection: Option<String>,
+    pub(crate) tora: Option<Metres>,
+    pub(crate) toda: Option<Metres>,
+    pub(crate) asda: Option<Metres>,
+    pub(crate) lda: Option<Metres>,
+    pub(crate) remarks: String,
+}
+
+impl DeclaredDistances {
+    /// The designator of the runway direction, such as `16`
+    pub fn runway(&self) -> &String {
+        &self.runway
+    }
+
+    /// The intersection for an intersection departure, such as `TWY B`, or `None` for the full
+    /// length of the runway
+    pub fn intersection(&self) -> Option<&String> {
+        self.intersection.as_ref()
+    }
+
+    /// The take-off run available
+    pub fn tora(&self) -> Option<Metres> {
+        self.tora
+    }
+
+    /// The take-off distance available
+    pub fn toda(&self) -> Option<Metres> {
+        self.toda
+    }
+
+    /// The accelerate-stop distance available
+    pub fn asda(&self) -> Option<Metres> {
+        self.asda
+    }
+
+    /// The landing distance available
+    pub fn lda(&self) -> Option<Metres> {
+        self.lda
+    }
+
+    /// Any remarks, or empty if there are none
+    pub fn remarks(&self) -> &String {
+        &self.remarks
+    }
+}
+
+/// An ATS communication facility at an aerodrome, from AD 2.18.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommunicationService {
+    pub(crate) service: String,
+    pub(crate) kind: CommunicationServiceKind,
+    pub(crate) call_sign: String,
+    pub(crate) frequencies_khz: Vec<usize>,
+    pub(crate) hours: String,
+    pub(crate) remarks: String,
+}
+
+impl CommunicationService {
+    /// The service designation, as written in the eAIP, such as `APP` or `ATIS`
+    pub fn service(&self) -> &String {
+        &self.service
+    }
+
+    /// The kind of service
+    pub fn kind(&self) -> CommunicationServiceKind {
+        self.kind
+    }
+
+    /// The call sign, such as `ABERDEEN TOWER`
+    pub fn call_sign(&self) -> &String {
+        &self.call_sign
+    }
+
+    /// The frequencies of the service, in kHz
+    pub fn frequencies_khz(&self) -> &Vec<usize> {
+        &self.frequencies_khz
+    }
+
+    /// The frequencies of the service, in MHz
+    pub fn frequencies(&self) -> Vec<f32> {
+        self.frequencies_khz
+            .iter()
+            .map(|f| *f as f32 / 1000f32)
+            .collect()
+    }
+
+    /// The hours of operation, as written in the eAIP
+    pub fn hours(&self) -> &String {
+        &self.hours
+    }
+
+    /// Any remarks, or empty if there are none
+    pub fn remarks(&self) -> &String {
+        &self.remarks
+    }
+}
+
+/// The kind of a [`CommunicationService`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CommunicationServiceKind {
+    /// Automatic terminal information service
+    ATIS,
+    /// Clearance delivery
+    Delivery,
+    /// Ground movement control
+    Ground,
+    /// Aerodrome control tower
+    Tower,
+    /// Approach control
+    Approach,
+    /// Radar, such as approach radar or director
+    Radar,
+    /// Aerodrome flight information service or air/ground radio
+    Information,
+    /// Any other service
+    #[default]
+    Other,
+}
+
 /// A chart
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chart {
     pub(crate) title: String,
     pub(crate) url: String,
@@ -251,3 +881,306 @@ impl Chart {
         &self.url
     }
 }
+
+/// An ATS airspace, such as an FIR or TMA.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Airspace {
+    pub(crate) name: String,
+    pub(crate) kind: AirspaceKind,
+    pub(crate) class: String,
+    pub(crate) upper_limit: String,
+    pub(crate) lower_limit: String,
+    pub(crate) lateral_limits: String,
+    pub(crate) boundary: Vec<(f64, f64)>,
+    pub(crate) unparsed_limits: Vec<String>,
+    pub(crate) call_signs: Vec<String>,
+    pub(crate) frequencies_khz: Vec<usize>,
+}
+
+impl Airspace {
+    /// The name of this airspace, such as `LONDON FIR`.
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// The type of this airspace.
+    pub fn kind(&self) -> AirspaceKind {
+        self.kind
+    }
+
+    /// The ICAO class of this airspace, such as `A`, or empty if not given.
+    pub fn class(&self) -> &String {
+        &self.class
+    }
+
+    /// The upper vertical limit of this airspace.
+    pub fn upper_limit(&self) -> &String {
+        &self.upper_limit
+    }
+
+    /// The lower vertical limit of this airspace.
+    pub fn lower_limit(&self) -> &String {
+        &self.lower_limit
+    }
+
+    /// The lateral limits of this airspace, as written in the eAIP.
+    pub fn lateral_limits(&self) -> &String {
+        &self.lateral_limits
+    }
+
+    /// The vertices of the lateral boundary of this airspace, as (latitude, longitude) pairs.
+    /// Circles and arcs are approximated by polygons.
+    pub fn boundary(&self) -> &Vec<(f64, f64)> {
+        &self.boundary
+    }
+
+    /// The parts of the lateral limits that couldn't be turned into the boundary, such as a
+    /// section along a national border.
+    pub fn unparsed_limits(&self) -> &Vec<String> {
+        &self.unparsed_limits
+    }
+
+    /// The call signs of the units providing services in this airspace.
+    pub fn call_signs(&self) -> &Vec<String> {
+        &self.call_signs
+    }
+
+    /// The frequencies of the units providing services in this airspace, in kilo-Hertz.
+    pub fn frequencies_khz(&self) -> &Vec<usize> {
+        &self.frequencies_khz
+    }
+}
+
+/// The type of an [`Airspace`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AirspaceKind {
+    /// A flight information region
+    FIR,
+    /// An upper flight information region
+    UIR,
+    /// A terminal control area
+    TMA,
+    /// A control area
+    CTA,
+    /// Any other type of airspace
+    #[default]
+    Other,
+}
+
+/// A prohibited, restricted or danger area.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RestrictedArea {
+    pub(crate) designator: String,
+    pub(crate) name: String,
+    pub(crate) kind: RestrictedAreaKind,
+    pub(crate) upper_limit: String,
+    pub(crate) lower_limit: String,
+    pub(crate) activity: String,
+    pub(crate) remarks: String,
+    pub(crate) lateral_limits: String,
+    pub(crate) boundary: Vec<(f64, f64)>,
+    pub(crate) unparsed_limits: Vec<String>,
+}
+
+impl RestrictedArea {
+    /// The designator of this area, such as `EG D201` or `EH R8`.
+    pub fn designator(&self) -> &String {
+        &self.designator
+    }
+
+    /// The name of this area.
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Whether this is a prohibited, restricted or danger area.
+    pub fn kind(&self) -> RestrictedAreaKind {
+        self.kind
+    }
+
+    /// The upper vertical limit of this area.
+    pub fn upper_limit(&self) -> &String {
+        &self.upper_limit
+    }
+
+    /// The lower vertical limit of this area.
+    pub fn lower_limit(&self) -> &String {
+        &self.lower_limit
+    }
+
+    /// When this area is active, as written in the eAIP, or empty if not given.
+    pub fn activity(&self) -> &String {
+        &self.activity
+    }
+
+    /// The remarks for this area, including the activity, as written in the eAIP.
+    pub fn remarks(&self) -> &String {
+        &self.remarks
+    }
+
+    /// The lateral limits of this area, as written in the eAIP.
+    pub fn lateral_limits(&self) -> &String {
+        &self.lateral_limits
+    }
+
+    /// The vertices of the lateral boundary of this area, as (latitude, longitude) pairs. Circles
+    /// and arcs are approximated by polygons.
+    pub fn boundary(&self) -> &Vec<(f64, f64)> {
+        &self.boundary
+    }
+
+    /// The parts of the lateral limits that couldn't be turned into the boundary.
+    pub fn unparsed_limits(&self) -> &Vec<String> {
+        &self.unparsed_limits
+    }
+}
+
+/// The kind of a [`RestrictedArea`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RestrictedAreaKind {
+    /// A prohibited area (P), which may not be entered
+    Prohibited,
+    /// A restricted area (R), which may only be entered under certain conditions
+    Restricted,
+    /// A danger area (D), within which activities dangerous to flight may exist
+    #[default]
+    Danger,
+}
+
+/// A published AIRAC cycle of an eAIP.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cycle {
+    #[cfg_attr(feature = "serde", serde(with = "airac_serde"))]
+    pub(crate) airac: AIRAC,
+    pub(crate) effective: NaiveDate,
+    pub(crate) published: Option<NaiveDate>,
+    pub(crate) location: String,
+}
+
+impl Cycle {
+    /// The AIRAC of this cycle.
+    pub fn airac(&self) -> &AIRAC {
+        &self.airac
+    }
+
+    /// The date this cycle becomes effective.
+    pub fn effective(&self) -> NaiveDate {
+        self.effective
+    }
+
+    /// The date this cycle was published, if the eAIP gives it.
+    pub fn published(&self) -> Option<NaiveDate> {
+        self.published
+    }
+
+    /// The location of the package for this cycle, relative to the base URI of the eAIP.
+    pub fn location(&self) -> &String {
+        &self.location
+    }
+
+    /// The status of this cycle today.
+    pub fn status(&self) -> CycleStatus {
+        let current = AIRAC::current();
+        if self.airac == current {
+            CycleStatus::Current
+        } else if self.airac > current {
+            CycleStatus::Pending
+        } else {
+            CycleStatus::Superseded
+        }
+    }
+}
+
+/// Whether a [`Cycle`] is in effect.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CycleStatus {
+    /// The cycle is currently effective
+    Current,
+    /// The cycle has been published, but isn't effective yet
+    Pending,
+    /// The cycle has been replaced by a newer cycle
+    Superseded,
+}
+
+/// Data fetched from an eAIP, along with the AIRAC cycle it was fetched for.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CycleData<T> {
+    #[cfg_attr(feature = "serde", serde(with = "airac_serde"))]
+    pub(crate) airac: AIRAC,
+    pub(crate) data: T,
+}
+
+impl<T> CycleData<T> {
+    /// The AIRAC the data was fetched for.
+    pub fn airac(&self) -> &AIRAC {
+        &self.airac
+    }
+
+    /// The date the data becomes, or became, effective.
+    pub fn effective(&self) -> NaiveDate {
+        self.airac.starts()
+    }
+
+    /// Is the data for a cycle that isn't effective yet?
+    pub fn is_pending(&self) -> bool {
+        self.airac > AIRAC::current()
+    }
+
+    /// The data.
+    pub fn data(&self) -> &T {
+        &self.data
+    }
+
+    /// Take the data, discarding the AIRAC.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+/// The lateral boundary of an airspace or area, parsed with a [`BoundaryParser`].
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Boundary {
+    pub(crate) vertices: Vec<(f64, f64)>,
+    pub(crate) unparsed: Vec<String>,
+}
+
+impl Boundary {
+    /// The vertices of the boundary, as (latitude, longitude) pairs in decimal degrees.
+    pub fn vertices(&self) -> &Vec<(f64, f64)> {
+        &self.vertices
+    }
+
+    /// The fragments of the description that couldn't be parsed, in the order they appear.
+    pub fn unparsed(&self) -> &Vec<String> {
+        &self.unparsed
+    }
+
+    /// Take the vertices, discarding any unparsed fragments.
+    pub fn into_vertices(self) -> Vec<(f64, f64)> {
+        self.vertices
+    }
+}
+
+/// Serialization of an [`AIRAC`] as the date it starts on, e.g. `2022-05-19`.
+#[cfg(feature = "serde")]
+pub(crate) mod airac_serde {
+    use airac::{Datelike, NaiveDate, AIRAC};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(airac: &AIRAC, serializer: S) -> Result<S::Ok, S::Error> {
+        airac.starts().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<AIRAC, D::Error> {
+        let date = NaiveDate::deserialize(deserializer)?;
+        Ok(AIRAC::from_ymd(date.year(), date.month(), date.day()))
+    }
+}
@@ -0,0 +1,349 @@
+use eaip::prelude::*;
+
+/// An AD 2 page in the style of NATS, cut down to the sections that are parsed.
+const EGPD: &str = r#"<html><body>
+<div>
+  <div class="TitleAD">EGPD — ABERDEEN/DYCE</div>
+  <div id="EGPD-AD-2.2">
+    <table><tbody>
+      <tr><td>1</td><td>ARP coordinates and site at AD</td><td>571209N 0021152W<br/>Mid point of RWY 16/34</td></tr>
+      <tr><td>2</td><td>Direction and distance from (city)</td><td>5 NM NW of Aberdeen</td></tr>
+      <tr><td>3</td><td>Elevation/Reference temperature</td><td>215 FT / 18°C</td></tr>
+    </tbody></table>
+  </div>
+  <div id="EGPD-AD-2.3">
+    <table><tbody>
+      <tr><td>1</td><td>AD Operator</td><td>H24</td></tr>
+      <tr><td>2</td><td>Customs and immigration</td><td>H24</td></tr>
+      <tr><td>3</td><td>Health and sanitation</td><td>NIL</td></tr>
+      <tr><td>4</td><td>AIS Briefing Office</td><td>NIL</td></tr>
+      <tr><td>5</td><td>ATS Reporting Office (ARO)</td><td>NIL</td></tr>
+      <tr><td>6</td><td>MET Briefing Office</td><td>NIL</td></tr>
+      <tr><td>7</td><td>ATS</td><td>H24</td></tr>
+      <tr><td>8</td><td>Fuelling</td><td>H24</td></tr>
+      <tr><td>9</td><td>Handling</td><td>H24</td></tr>
+      <tr><td>10</td><td>Security</td><td>H24</td></tr>
+      <tr><td>11</td><td>De-icing</td><td>H24</td></tr>
+      <tr><td>12</td><td>Remarks</td><td>NIL</td></tr>
+    </tbody></table>
+  </div>
+  <div id="EGPD-AD-2.4">
+    <table><tbody>
+      <tr><td>1</td><td>Cargo-handling facilities</td><td>Available.</td></tr>
+      <tr><td>2</td><td>Fuel/oil types</td><td>AVGAS 100LL, JET A-1.<br/>Oils: Normal grades.</td></tr>
+      <tr><td>3</td><td>Fuelling facilities/capacity</td><td>Bowsers.</td></tr>
+      <tr><td>4</td><td>De-icing facilities</td><td>Available.</td></tr>
+      <tr><td>5</td><td>Hangar space for visiting aircraft</td><td>NIL</td></tr>
+      <tr><td>6</td><td>Repair facilities for visiting aircraft</td><td>Limited.</td></tr>
+      <tr><td>7</td><td>Remarks</td><td>NIL</td></tr>
+    </tbody></table>
+  </div>
+  <div id="EGPD-AD-2.5">
+    <table><tbody>
+      <tr><td>1</td><td>Hotels</td><td>In city and at aerodrome.</td></tr>
+      <tr><td>2</td><td>Restaurants</td><td>At aerodrome.</td></tr>
+    </tbody></table>
+  </div>
+  <div id="EGPD-AD-2.6">
+    <table><tbody>
+      <tr><td>1</td><td>AD category for fire fighting</td><td>RFF Category 7.</td></tr>
+      <tr><td>2</td><td>Rescue equipment</td><td>NIL</td></tr>
+      <tr><td>3</td><td>Capability for removal of disabled aircraft</td><td>Nearest equipment held at Heathrow.</td></tr>
+      <tr><td>4</td><td>Remarks</td><td>NIL</td></tr>
+    </tbody></table>
+  </div>
+  <div id="EGPD-AD-2.7">
+    <table><tbody>
+      <tr><td>1</td><td>Types of clearing equipment</td><td>NIL</td></tr>
+      <tr><td>2</td><td>Clearance priorities</td><td>NIL</td></tr>
+      <tr><td>3</td><td>Remarks</td><td>Aerodrome available all year.</td></tr>
+    </tbody></table>
+  </div>
+  <div id="EGPD-AD-2.12">
+    <table>
+      <thead>
+        <tr><th>Designations RWY NR</th><th>TRUE BRG</th><th>Dimensions of RWY (M)</th><th>Strength (PCN) and surface of RWY and SWY</th><th>THR co-ordinates</th><th>THR elevation and highest elevation of TDZ of precision APP RWY</th></tr>
+        <tr><th>1</th><th>2</th><th>3</th><th>4</th><th>5</th><th>6</th></tr>
+      </thead>
+      <tbody>
+        <tr><td>16</td><td>155.74° T<br/>158° MAG</td><td>1953 x 46</td><td>PCN 49/F/B/W/T<br/>Asphalt</td><td>571239.66N 0021224.71W<br/>GUND 170 FT</td><td>THR 206.3 FT<br/>TDZ 215 FT</td></tr>
+        <tr><td>34</td><td>335.75°</td><td>1953 x 46</td><td>PCN 49/F/B/W/T Asphalt</td><td>571142.12N 0021140.05W</td><td>THR 210 FT</td></tr>
+      </tbody>
+    </table>
+    <table><tbody>
+      <tr><td>16</td><td>0.4%</td><td>NIL</td><td>NIL</td><td>NIL</td><td>NIL</td></tr>
+    </tbody></table>
+  </div>
+  <div id="EGPD-AD-2.13">
+    <table>
+      <thead><tr><th>RWY Designator</th><th>TORA (M)</th><th>TODA (M)</th><th>ASDA (M)</th><th>LDA (M)</th><th>Remarks</th></tr></thead>
+      <tbody>
+        <tr><td>16</td><td>1953</td><td>2133</td><td>1953</td><td>1838</td><td>NIL</td></tr>
+        <tr><td>16 (from TWY B)</td><td>1500</td><td>1680</td><td>1500</td><td>NU</td><td>NIL</td></tr>
+        <tr><td>34</td><td>1953</td><td>2110</td><td>1953</td><td>1953</td><td>NIL</td></tr>
+        <tr><td>34</td><td>1450</td><td>1607</td><td>1450</td><td>-</td><td>Take-off from intersection with TWY D</td></tr>
+      </tbody>
+    </table>
+  </div>
+  <div id="EGPD-AD-2.18">
+    <table>
+      <thead><tr><th>Service designation</th><th>Call sign</th><th>Channel</th><th>SATVOICE</th><th>Logon address</th><th>Hours of operation</th><th>Remarks</th></tr></thead>
+      <tbody>
+        <tr><td>APP</td><td>ABERDEEN APPROACH</td><td>119.055 MHz</td><td>NIL</td><td>NIL</td><td>H24</td><td>NIL</td></tr>
+        <tr><td>RAD</td><td>ABERDEEN RADAR</td><td>128.305<br/>121.255 MHz</td><td>NIL</td><td>NIL</td><td>H24</td><td>Radar services.</td></tr>
+        <tr><td></td><td></td><td>134.105 MHz</td><td>NIL</td><td>NIL</td><td>HO</td><td>Secondary frequency.</td></tr>
+        <tr><td>TWR</td><td>ABERDEEN TOWER</td><td>118.105 MHz</td><td>NIL</td><td>NIL</td><td>H24</td><td>NIL</td></tr>
+        <tr><td>GND</td><td>ABERDEEN GROUND</td><td>121.705 MHz</td><td>NIL</td><td>NIL</td><td>H24</td><td>NIL</td></tr>
+        <tr><td>ATIS</td><td>ABERDEEN INFORMATION</td><td>121.855 MHz</td><td>NIL</td><td>NIL</td><td>H24</td><td>Also on ADN VOR.</td></tr>
+      </tbody>
+    </table>
+  </div>
+  <div id="EGPD-AD-2.19">
+    <table>
+      <thead><tr><th>Type of aid, MAG VAR, Type of supported OPS</th><th>ID</th><th>Frequency</th><th>Hours of operation</th><th>Position of transmitting antenna co-ordinates</th><th>Elevation of DME transmitting antenna</th><th>Service volume radius from GBAS reference point</th><th>Remarks</th></tr></thead>
+      <tbody>
+        <tr><td>ILS/LOC RWY 16<br/>(1°W - 2022)<br/>CAT III</td><td>I-ABD</td><td>109.900 MHz</td><td>H24</td><td>571124.80N 0021050.10W</td><td>NIL</td><td>NIL</td><td>LOC course 156° MAG.</td></tr>
+        <tr><td>GP 16<br/>3°</td><td>NIL</td><td>333.800 MHz</td><td>H24</td><td>571234.10N 0021207.50W</td><td>NIL</td><td>NIL</td><td>RDH 54 FT.</td></tr>
+        <tr><td>DME RWY 16</td><td>I-ABD</td><td>CH36X</td><td>H24</td><td>571234.10N 0021207.50W</td><td>260 FT</td><td>NIL</td><td>Freq paired with ILS.</td></tr>
+        <tr><td>OM RWY 16</td><td>NIL</td><td>75 MHz</td><td>H24</td><td>571705.00N 0021730.00W</td><td>NIL</td><td>NIL</td><td>NIL</td></tr>
+        <tr><td>L</td><td>ATF</td><td>348 kHz</td><td>H24</td><td>571437.21N 0021956.54W</td><td>NIL</td><td>NIL</td><td>Range 15 NM.</td></tr>
+      </tbody>
+    </table>
+  </div>
+  <div id="EGPD-AD-2.24">
+    <table><tbody>
+      <tr><td>Aerodrome Chart</td><td><a href="EGPD-2-1.pdf">AD 2.EGPD-2-1</a></td></tr>
+    </tbody></table>
+  </div>
+</div>
+</body></html>"#;
+
+/// An AD 2 page in the style of LVNL, for an aerodrome below sea level, cut down to the sections
+/// with elevations.
+const EHAM: &str = r#"<html><body>
+<div>
+  <div class="TitleAD">EHAM — AMSTERDAM/Schiphol</div>
+  <div id="EHAM-AD-2.2">
+    <table><tbody>
+      <tr><td>1</td><td>ARP coordinates and site at AD</td><td>521829N 0044551E</td></tr>
+      <tr><td>2</td><td>Direction and distance from (city)</td><td>9 KM SW of Amsterdam</td></tr>
+      <tr><td>3</td><td>Elevation/Reference temperature</td><td>-11 FT / 21°C</td></tr>
+    </tbody></table>
+  </div>
+  <div id="EHAM-AD-2.12">
+    <table><tbody>
+      <tr><td>18R</td><td>183.23° T</td><td>3800 x 60</td><td>PCN 90/F/A/W/T<br/>Asphalt</td><td>522141.53N 0044241.81E</td><td>THR -12.1 FT<br/>TDZ -11.3 FT</td></tr>
+      <tr><td>36C</td><td>000.00° T<br/>359° MAG<br/>VAR 1° E</td><td>3300 x 45</td><td>PCN 90/F/A/W/T<br/>Asphalt</td><td>521850.46N 0044417.40E</td><td>THR -15 FT</td></tr>
+    </tbody></table>
+  </div>
+  <div id="EHAM-AD-2.19">
+    <table><tbody>
+      <tr><td>DME RWY 18R</td><td>ZWA</td><td>CH48X</td><td>H24</td><td>522130.21N 0044250.00E</td><td>-9.6 FT</td><td>NIL</td><td>NIL</td></tr>
+      <tr><td>LOC 18R</td><td>ZWA</td><td>110.100 MHz</td><td>H24</td><td>521802.80N 0044411.20E</td><td>NIL</td><td>NIL</td><td>NIL</td></tr>
+      <tr><td>GP<br/>3°</td><td>ZWA</td><td>334.700 MHz</td><td>H24</td><td>522128.50N 0044259.70E</td><td>NIL</td><td>NIL</td><td>NIL</td></tr>
+    </tbody></table>
+  </div>
+</div>
+</body></html>"#;
+
+#[test]
+fn test_parse_airport() -> anyhow::Result<()> {
+    let airport = Airport::parse(EGPD)?;
+    assert_eq!("EGPD", airport.icao());
+    assert_eq!("ABERDEEN/DYCE", airport.name());
+    assert_eq!(215, airport.elevation());
+    assert_eq!(1, airport.charts().len());
+    Ok(())
+}
+
+#[test]
+fn test_parse_runways() -> anyhow::Result<()> {
+    let airport = Airport::parse(EGPD)?;
+    let runways = airport.runways();
+    assert_eq!(2, runways.len());
+
+    let rwy16 = &runways[0];
+    assert_eq!("16", rwy16.designator());
+    assert_eq!(155.74, rwy16.true_bearing());
+    assert_eq!(Some(158.0), rwy16.magnetic_bearing());
+    assert_eq!(1953, rwy16.length());
+    assert_eq!(46, rwy16.width());
+    assert_eq!("PCN 49/F/B/W/T", rwy16.strength());
+    assert_eq!("Asphalt", rwy16.surface());
+    assert!((rwy16.threshold_latitude() - 57.211016667).abs() < 1e-9);
+    assert!((rwy16.threshold_longitude() + 2.206863889).abs() < 1e-9);
+    assert_eq!(206.3, rwy16.threshold_elevation());
+    assert_eq!(Some(215.0), rwy16.tdz_elevation());
+
+    let rwy34 = &runways[1];
+    assert_eq!("34", rwy34.designator());
+    assert_eq!(335.75, rwy34.true_bearing());
+    assert_eq!(None, rwy34.magnetic_bearing());
+    assert_eq!("PCN 49/F/B/W/T", rwy34.strength());
+    assert_eq!("Asphalt", rwy34.surface());
+    assert_eq!(210.0, rwy34.threshold_elevation());
+    assert_eq!(None, rwy34.tdz_elevation());
+    Ok(())
+}
+
+#[test]
+fn test_parse_below_sea_level() -> anyhow::Result<()> {
+    let airport = Airport::parse(EHAM)?;
+    assert_eq!(-11, airport.elevation());
+
+    let rwy18r = &airport.runways()[0];
+    assert_eq!(-12.1, rwy18r.threshold_elevation());
+    assert_eq!(Some(-11.3), rwy18r.tdz_elevation());
+
+    // A true bearing of zero is kept, rather than replaced by a later bearing.
+    let rwy36c = &airport.runways()[1];
+    assert_eq!(0.0, rwy36c.true_bearing());
+    assert_eq!(Some(359.0), rwy36c.magnetic_bearing());
+    assert_eq!(-15.0, rwy36c.threshold_elevation());
+
+    assert_eq!(-10, airport.navaids()[0].elevation());
+
+    // A glidepath without a runway serves the runway of the localiser before it.
+    let gp = &airport.navaids()[2];
+    assert_eq!(NavAidKind::GP, gp.kind());
+    assert_eq!(Some(&"18R".to_string()), gp.runway());
+    Ok(())
+}
+
+#[test]
+fn test_parse_declared_distances() -> anyhow::Result<()> {
+    let airport = Airport::parse(EGPD)?;
+    assert_eq!(4, airport.declared_distances().len());
+
+    let rwy16 = airport.declared_distances_for("16");
+    assert_eq!(2, rwy16.len());
+    assert_eq!(None, rwy16[0].intersection());
+    assert_eq!(Some(Metres(1953)), rwy16[0].tora());
+    assert_eq!(Some(Metres(2133)), rwy16[0].toda());
+    assert_eq!(Some(Metres(1953)), rwy16[0].asda());
+    assert_eq!(Some(Metres(1838)), rwy16[0].lda());
+    assert_eq!("", rwy16[0].remarks());
+    assert_eq!(Some(&"TWY B".to_string()), rwy16[1].intersection());
+    assert_eq!(Some(Metres(1500)), rwy16[1].tora());
+    assert_eq!(None, rwy16[1].lda());
+
+    let rwy34 = airport.declared_distances_for("34");
+    assert_eq!(None, rwy34[0].intersection());
+    assert_eq!(Some(&"TWY D".to_string()), rwy34[1].intersection());
+    assert_eq!(None, rwy34[1].lda());
+    assert_eq!("Take-off from intersection with TWY D", rwy34[1].remarks());
+
+    assert_eq!("1953 m", rwy34[0].tora().unwrap().to_string());
+    assert_eq!(6407, rwy34[0].tora().unwrap().feet());
+    Ok(())
+}
+
+#[test]
+fn test_parse_communications() -> anyhow::Result<()> {
+    let airport = Airport::parse(EGPD)?;
+    let services = airport.communications();
+    assert_eq!(5, services.len());
+
+    assert_eq!("APP", services[0].service());
+    assert_eq!(CommunicationServiceKind::Approach, services[0].kind());
+    assert_eq!("ABERDEEN APPROACH", services[0].call_sign());
+    assert_eq!(&vec![119055], services[0].frequencies_khz());
+    assert_eq!("H24", services[0].hours());
+    assert_eq!("", services[0].remarks());
+
+    let radar = airport.communications_of(CommunicationServiceKind::Radar);
+    assert_eq!(1, radar.len());
+    assert_eq!(&vec![128305, 121255, 134105], radar[0].frequencies_khz());
+    assert_eq!("H24 HO", radar[0].hours());
+    assert_eq!("Radar services. Secondary frequency.", radar[0].remarks());
+
+    let tower = airport.communications_of(CommunicationServiceKind::Tower);
+    assert_eq!(vec![118.105], tower[0].frequencies());
+    let ground = airport.communications_of(CommunicationServiceKind::Ground);
+    assert_eq!(&vec![121705], ground[0].frequencies_khz());
+    let atis = airport.communications_of(CommunicationServiceKind::ATIS);
+    assert_eq!("ABERDEEN INFORMATION", atis[0].call_sign());
+    assert_eq!("Also on ADN VOR.", atis[0].remarks());
+    Ok(())
+}
+
+#[test]
+fn test_parse_navaids() -> anyhow::Result<()> {
+    let airport = Airport::parse(EGPD)?;
+    let navaids = airport.navaids();
+    assert_eq!(5, navaids.len());
+
+    let loc = &navaids[0];
+    assert_eq!(NavAidKind::LOC, loc.kind());
+    assert_eq!("I-ABD", loc.id());
+    assert_eq!(109900, loc.frequency_khz());
+    assert_eq!(Some(&"16".to_string()), loc.runway());
+    assert_eq!(Some(156.0), loc.course());
+    assert_eq!(None, loc.glidepath_angle());
+    assert!((loc.latitude() - 57.190222222).abs() < 1e-9);
+    assert!(loc.kind().is_aerodrome());
+
+    let gp = &navaids[1];
+    assert_eq!(NavAidKind::GP, gp.kind());
+    assert_eq!("", gp.id());
+    assert_eq!(333800, gp.frequency_khz());
+    assert_eq!(Some(3.0), gp.glidepath_angle());
+    assert_eq!(Some(&"16".to_string()), gp.runway());
+
+    let dme = &navaids[2];
+    assert_eq!(NavAidKind::DME, dme.kind());
+    assert_eq!(Some(&"CH36X".to_string()), dme.channel());
+    assert_eq!(109900, dme.frequency_khz());
+    assert_eq!(260, dme.elevation());
+    assert_eq!(Some(&"16".to_string()), dme.runway());
+    assert!(!dme.kind().is_aerodrome());
+
+    assert_eq!(NavAidKind::OM, navaids[3].kind());
+    assert_eq!(75000, navaids[3].frequency_khz());
+
+    let locator = &navaids[4];
+    assert_eq!(NavAidKind::Locator, locator.kind());
+    assert_eq!("ATF", locator.id());
+    assert_eq!(348, locator.frequency_khz());
+    Ok(())
+}
+
+#[test]
+fn test_parse_hours_and_services() -> anyhow::Result<()> {
+    let airport = Airport::parse(EGPD)?;
+
+    let hours = airport.operational_hours();
+    assert_eq!("H24", hours.operator());
+    assert_eq!("H24", hours.customs());
+    assert!(hours.has_customs());
+    assert_eq!("", hours.ais_briefing());
+    assert!(!hours.has_ais_briefing());
+    assert_eq!("H24", hours.de_icing());
+    assert_eq!("", hours.remarks());
+
+    let handling = airport.handling_services();
+    assert_eq!(
+        "AVGAS 100LL, JET A-1. Oils: Normal grades.",
+        handling.fuel()
+    );
+    assert_eq!(
+        &vec!["AVGAS 100LL".to_string(), "JET A-1".to_string()],
+        handling.fuel_types()
+    );
+    assert!(handling.has_de_icing());
+    assert_eq!("", handling.hangar_space());
+
+    let facilities = airport.passenger_facilities();
+    assert_eq!("In city and at aerodrome.", facilities.hotels());
+    assert_eq!("", facilities.medical());
+
+    let rescue = airport.rescue_firefighting();
+    assert_eq!("RFF Category 7.", rescue.category());
+    assert_eq!(Some(7), rescue.category_number());
+    assert_eq!("Nearest equipment held at Heathrow.", rescue.removal());
+
+    let seasonal = airport.seasonal_availability();
+    assert!(!seasonal.has_clearing());
+    assert_eq!("Aerodrome available all year.", seasonal.remarks());
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use airac::AIRAC;
+use eaip::prelude::*;
+
+/// ENR 2.1 in the style of NATS, with vertical limits in a nested table and services over
+/// several rows.
+const NATS: &str = r#"<html><body><table>
+<thead><tr><th>Name</th><th>Unit</th><th>Call sign</th><th>Frequency</th><th>Remarks</th></tr></thead>
+<tbody>
+<tr>
+  <td>
+    <p><strong><span class="SD">LONDON FIR</span><span class="sdParams" style="display: none;">AIRSPACE;TXT_NAME;1</span></strong></p>
+    <p>550000N 0050000W -</p>
+    <p>545300N 0045200W -</p>
+    <p>500000N 0020000W -</p>
+    <p>550000N 0050000W</p>
+    <table class="vertical"><tbody>
+      <tr><td class="Upper">FL 245</td></tr>
+      <tr><td class="Lower">SFC</td></tr>
+    </tbody></table>
+    <p>Class: G</p>
+  </td>
+  <td>LONDON ACC</td>
+  <td>LONDON INFORMATION<br/>EN<br/>H24</td>
+  <td>124.600 MHz</td>
+  <td></td>
+</tr>
+<tr>
+  <td></td>
+  <td>LONDON ACC</td>
+  <td>LONDON INFORMATION<br/>EN</td>
+  <td>125.475 MHz</td>
+  <td></td>
+</tr>
+<tr>
+  <td>
+    <p><strong>ESSEX TMA 1</strong></p>
+    <p>515000N 0001000E - 514500N 0003000E - 513000N 0000000W</p>
+    <p>FL 195</p>
+    <p>3500 FT ALT</p>
+  </td>
+  <td>A</td>
+  <td>LONDON TERMINAL CONTROL</td>
+  <td>ESSEX RADAR</td>
+  <td>120.625</td>
+</tr>
+</tbody>
+</table></body></html>"#;
+
+/// ENR 2.1 in the style of LVNL, with limits and class given in the text.
+const LVNL: &str = r#"<html><body><table><tbody>
+<tr>
+  <td>AMSTERDAM FIR<br/>531000N 0030000E - 520000N 0070000E - 510000N 0030000E<br/>Upper limit: FL 195<br/>Lower limit: GND<br/>Class G</td>
+  <td>Amsterdam ACC</td>
+  <td>DUTCH MIL INFO</td>
+  <td>132.350 MHz<br/>119.175 MHz</td>
+</tr>
+<tr>
+  <td>SCHIPHOL TMA 1<br/>524000N 0043000E - 522000N 0050000E - 521000N 0043000E<br/>FL 095<br/>1500 FT AMSL</td>
+  <td>Schiphol APP</td>
+  <td>SCHIPHOL APPROACH</td>
+  <td>119.055<br/>MHz</td>
+  <td>a</td>
+</tr>
+</tbody></table></body></html>"#;
+
+#[tokio::test]
+async fn test_nats_airspaces() -> anyhow::Result<()> {
+    let airac = AIRAC::from_ymd(2022, 5, 19);
+    let eaip = EAIP::new("https://example.com", "EG", "en-GB");
+    let mut source = MemorySource::new();
+    source.insert_page(
+        &eaip,
+        airac.clone(),
+        Part::EnRoute(ENR::ATSAirspace(1)),
+        EAIPType::HTML,
+        NATS,
+    );
+    let eaip = eaip.with_source(source);
+
+    let airspaces = Airspaces::from_eaip(&eaip, airac).await?;
+    assert_eq!(2, airspaces.len());
+
+    let fir = &airspaces[0];
+    assert_eq!("LONDON FIR", fir.name());
+    assert_eq!(AirspaceKind::FIR, fir.kind());
+    assert_eq!("G", fir.class());
+    assert_eq!("FL 245", fir.upper_limit());
+    assert_eq!("SFC", fir.lower_limit());
+    assert_eq!(4, fir.boundary().len());
+    assert_eq!((55.0, -5.0), fir.boundary()[0]);
+    assert_eq!(&vec!["LONDON INFORMATION".to_string()], fir.call_signs());
+    assert_eq!(&vec![124600, 125475], fir.frequencies_khz());
+
+    let tma = &airspaces[1];
+    assert_eq!("ESSEX TMA 1", tma.name());
+    assert_eq!(AirspaceKind::TMA, tma.kind());
+    assert_eq!("A", tma.class());
+    assert_eq!("FL 195", tma.upper_limit());
+    assert_eq!("3500 FT ALT", tma.lower_limit());
+    assert_eq!(3, tma.boundary().len());
+    assert_eq!(&vec!["ESSEX RADAR".to_string()], tma.call_signs());
+    assert_eq!(&vec![120625], tma.frequencies_khz());
+    Ok(())
+}
+
+#[test]
+fn test_lvnl_airspaces() -> anyhow::Result<()> {
+    let airspaces = Airspaces::parse(LVNL)?;
+    assert_eq!(2, airspaces.len());
+
+    let fir = &airspaces[0];
+    assert_eq!("AMSTERDAM FIR", fir.name());
+    assert_eq!("G", fir.class());
+    assert_eq!("FL 195", fir.upper_limit());
+    assert_eq!("GND", fir.lower_limit());
+    assert_eq!(
+        "531000N 0030000E - 520000N 0070000E - 510000N 0030000E",
+        fir.lateral_limits()
+    );
+    assert_eq!(3, fir.boundary().len());
+    assert_eq!(&vec!["DUTCH MIL INFO".to_string()], fir.call_signs());
+    assert_eq!(&vec![132350, 119175], fir.frequencies_khz());
+
+    // A lone letter in the remarks isn't the class.
+    let tma = &airspaces[1];
+    assert_eq!("SCHIPHOL TMA 1", tma.name());
+    assert_eq!("", tma.class());
+    assert_eq!(&vec![119055], tma.frequencies_khz());
+    Ok(())
+}
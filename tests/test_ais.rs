@@ -45,6 +45,26 @@ macro_rules! test_ais {
                         EAIPType::HTML,
                     )
                 })?;
+            eprintln!("Test airspaces");
+            let _airspaces = Airspaces::from_eaip(eaip, airac.clone())
+                .await
+                .with_context(|| {
+                    eaip.generate_url(
+                        airac.clone(),
+                        Part::EnRoute(ENR::ATSAirspace(1)),
+                        EAIPType::HTML,
+                    )
+                })?;
+            eprintln!("Test restricted areas");
+            let _areas = RestrictedAreas::from_eaip(eaip, airac.clone())
+                .await
+                .with_context(|| {
+                    eaip.generate_url(
+                        airac.clone(),
+                        Part::EnRoute(ENR::NavWarnings(1)),
+                        EAIPType::HTML,
+                    )
+                })?;
             eprintln!("Test airport list");
             let airports = Airports::from_eaip(eaip, airac.clone())
                 .await
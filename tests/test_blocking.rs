@@ -0,0 +1,32 @@
+#![cfg(feature = "blocking")]
+
+use airac::AIRAC;
+use eaip::blocking::{self, FromEAIP};
+use eaip::prelude::{EAIPType, Intersections, MemorySource, Part, EAIP, ENR};
+
+const INTERSECTIONS: &str = r#"<html><body><table><tbody>
+<tr class="Table-row-type-3"><td>ABBEW</td><td>501028N 0010350W</td></tr>
+</tbody></table></body></html>"#;
+
+#[test]
+fn test_blocking() -> anyhow::Result<()> {
+    let airac = AIRAC::from_ymd(2022, 5, 19);
+    let part = Part::EnRoute(ENR::RadioNavAids(4));
+    let eaip = EAIP::new("https://example.com", "EG", "en-GB");
+    let mut source = MemorySource::new();
+    source.insert_page(
+        &eaip,
+        airac.clone(),
+        part.clone(),
+        EAIPType::HTML,
+        INTERSECTIONS,
+    );
+    let eaip = eaip.with_source(source);
+
+    let page = blocking::get_page(&eaip, airac.clone(), part, EAIPType::HTML)?;
+    assert_eq!(INTERSECTIONS, page);
+
+    let intersections = Intersections::from_eaip(&eaip, airac)?;
+    assert_eq!("ABBEW", intersections[0].designator());
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use airac::AIRAC;
+use eaip::prelude::*;
+
+const INTERSECTIONS: &str = r#"<html><body><table><tbody>
+<tr class="Table-row-type-3"><td>ABBEW</td><td>501028N 0010350W</td></tr>
+</tbody></table></body></html>"#;
+
+#[tokio::test]
+async fn test_cached_source() -> anyhow::Result<()> {
+    let dir = std::env::temp_dir().join(format!("eaip-cache-test-{}", std::process::id()));
+    let eaip = EAIP::new("https://example.com", "EG", "en-GB");
+    let past = AIRAC::from_ymd(2022, 5, 19);
+    let part = Part::EnRoute(ENR::RadioNavAids(4));
+
+    let mut inner = MemorySource::new();
+    inner.insert_page(
+        &eaip,
+        past.clone(),
+        part.clone(),
+        EAIPType::HTML,
+        INTERSECTIONS,
+    );
+    let cache = CachedSource::new(inner, &dir);
+
+    let first = cache
+        .get_page(&eaip, past.clone(), part.clone(), EAIPType::HTML)
+        .await?;
+    let second = cache
+        .get_page(&eaip, past.clone(), part.clone(), EAIPType::HTML)
+        .await?;
+    assert_eq!(first, second);
+
+    let stats = cache.stats()?;
+    assert_eq!(1, stats.misses());
+    assert_eq!(1, stats.hits());
+    assert_eq!(1, stats.entries());
+
+    assert_eq!(0, cache.purge_airac(&past.next())?);
+    assert_eq!(1, cache.purge_airac(&past)?);
+    assert_eq!(0, cache.stats()?.entries());
+
+    let eaip = eaip.with_source(cache);
+    let intersections = Intersections::from_eaip(&eaip, past).await?;
+    assert_eq!("ABBEW", intersections[0].designator());
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cached_source_keeps_stale_pages() -> anyhow::Result<()> {
+    let dir = std::env::temp_dir().join(format!("eaip-cache-stale-test-{}", std::process::id()));
+    let eaip = EAIP::new("https://example.com", "EG", "en-GB");
+    let current = AIRAC::current();
+    let part = Part::EnRoute(ENR::RadioNavAids(4));
+
+    let mut inner = MemorySource::new();
+    inner.insert_page(
+        &eaip,
+        current.clone(),
+        part.clone(),
+        EAIPType::HTML,
+        INTERSECTIONS,
+    );
+    CachedSource::new(inner, &dir)
+        .get_page(&eaip, current.clone(), part.clone(), EAIPType::HTML)
+        .await?;
+
+    // Revalidating against a source without the page keeps and uses the cached copy.
+    let cache = CachedSource::new(MemorySource::new(), &dir).with_ttl(std::time::Duration::ZERO);
+    let page = cache
+        .get_page(&eaip, current.clone(), part.clone(), EAIPType::HTML)
+        .await?;
+    assert_eq!(INTERSECTIONS, page);
+    assert_eq!(1, cache.stats()?.revalidations());
+    assert_eq!(1, cache.stats()?.entries());
+
+    // Another eAIP at the same base URI doesn't share the cached page.
+    let other = EAIP::new("https://example.com", "EH", "en-GB");
+    assert!(matches!(
+        cache
+            .get_page(&other, current.clone(), part.clone(), EAIPType::HTML)
+            .await,
+        Err(Error::EAIPMissingPage(..))
+    ));
+
+    // Nor does the same eAIP with a different publishing offset.
+    let other = EAIP::new_with_offset(
+        "https://example.com",
+        "EG",
+        "en-GB",
+        chrono::Duration::weeks(2),
+    );
+    assert!(matches!(
+        cache.get_page(&other, current, part, EAIPType::HTML).await,
+        Err(Error::EAIPMissingPage(..))
+    ));
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
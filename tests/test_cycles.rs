@@ -0,0 +1,87 @@
+use airac::{NaiveDate, AIRAC};
+use eaip::prelude::*;
+
+const HISTORY: &str = r#"<html><body><table>
+<thead><tr><th>Effective date</th><th>Publication date</th><th>Link</th></tr></thead>
+<tbody>
+<tr><td>14 JUL 2022</td><td>02 JUN 2022</td><td><a href="2022-06-30-AIRAC/html/index-en-GB.html">AIRAC AMDT 07/2022</a></td></tr>
+<tr><td>16 JUN 2022</td><td>05 MAY 2022</td><td><a href="2022-06-02-AIRAC/html/index-en-GB.html">AIRAC AMDT 06/2022</a></td></tr>
+<tr><td></td><td></td><td><a href="2022-05-05-AIRAC/html/index-en-GB.html">AIRAC AMDT 05/2022</a></td></tr>
+</tbody>
+</table></body></html>"#;
+
+const LVNL_INDEX: &str = r#"<html><body>
+<h1>Integrated Aeronautical Information Package</h1>
+<h2>Currently Effective Issue</h2>
+<ul>
+<li><a href="2022-05-05-AIRAC/html/index-en-GB.html">AIRAC AMDT 05/2022</a><br/>Effective date: 19 MAY 2022<br/>Publication date: 07 APR 2022</li>
+</ul>
+<h2>Next Issues</h2>
+<ul>
+<li><a href="2022-06-02-AIRAC/html/index-en-GB.html">AIRAC AMDT 06/2022</a><br/>Effective date: 16 JUN 2022<br/>Publication date: 05 MAY 2022</li>
+<li><a href="2022-06-30-AIRAC/html/index-en-GB.html">AIRAC AMDT 07/2022</a><br/>Effective date: 14 JUL 2022<br/>Publication date: 02 JUN 2022</li>
+</ul>
+<h2>Expired Issues</h2>
+<ul>
+<li><a href="2022-04-07-AIRAC/html/index-en-GB.html">AIRAC AMDT 04/2022</a><br/>Effective date: 21 APR 2022<br/>Publication date: 10 MAR 2022</li>
+</ul>
+</body></html>"#;
+
+#[tokio::test]
+async fn test_get_cycles() -> anyhow::Result<()> {
+    let mut source = MemorySource::new();
+    source.insert("/history-en-GB.html", HISTORY);
+    let eaip = EAIP::new("https://example.com", "EH", "en-GB").with_source(source);
+
+    let cycles = eaip.get_cycles().await?;
+    assert_eq!(3, cycles.len());
+
+    assert_eq!(&AIRAC::from_ymd(2022, 5, 19), cycles[0].airac());
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2022, 5, 19),
+        Some(cycles[0].effective())
+    );
+    assert_eq!(None, cycles[0].published());
+
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2022, 6, 16),
+        Some(cycles[1].effective())
+    );
+    assert_eq!(NaiveDate::from_ymd_opt(2022, 5, 5), cycles[1].published());
+    assert_eq!("/2022-06-02-AIRAC", cycles[1].location());
+
+    assert_eq!(&AIRAC::from_ymd(2022, 7, 14), cycles[2].airac());
+    assert_eq!(CycleStatus::Superseded, cycles[2].status());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_cycles_lvnl() -> anyhow::Result<()> {
+    let eaip = eaip::eaip::ais::NL.eaip();
+    assert_eq!("/index.html", eaip.history_location());
+
+    let mut source = MemorySource::new();
+    source.insert("/index.html", LVNL_INDEX);
+    let eaip = eaip.clone().with_source(source);
+
+    let cycles = eaip.get_cycles().await?;
+    assert_eq!(4, cycles.len());
+    assert_eq!(&AIRAC::from_ymd(2022, 4, 21), cycles[0].airac());
+    assert_eq!("/2022-04-07-AIRAC", cycles[0].location());
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2022, 5, 19),
+        Some(cycles[1].effective())
+    );
+    assert_eq!(NaiveDate::from_ymd_opt(2022, 4, 7), cycles[1].published());
+    assert_eq!("/2022-06-30-AIRAC", cycles[3].location());
+    assert_eq!(NaiveDate::from_ymd_opt(2022, 6, 2), cycles[3].published());
+    Ok(())
+}
+
+#[test]
+fn test_history_location() {
+    let eaip = EAIP::new("https://example.com", "EG", "en-GB");
+    assert_eq!("/history-en-GB.html", eaip.history_location());
+    let eaip = eaip.with_history_location("/index.html");
+    assert_eq!("/index.html", eaip.history_location());
+}
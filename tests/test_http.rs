@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use eaip::prelude::*;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Serve each of the given raw HTTP responses, in order, to one request each. Returns the base
+/// URI of the server.
+async fn serve(responses: Vec<&'static str>) -> std::io::Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let base_uri = format!("http://{}", listener.local_addr()?);
+    tokio::spawn(async move {
+        for response in responses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 4096];
+            let _ = stream.read(&mut buf).await.unwrap();
+            stream.write_all(response.as_bytes()).await.unwrap();
+            stream.shutdown().await.unwrap();
+        }
+    });
+    Ok(base_uri)
+}
+
+fn source(max_retries: u32) -> ReqwestSource {
+    ReqwestSource::new().with_retry_policy(
+        RetryPolicy::new(max_retries)
+            .with_backoff(Duration::from_millis(10), Duration::from_millis(10))
+            .with_jitter(false),
+    )
+}
+
+const UNAVAILABLE: &str =
+    "HTTP/1.1 503 Service Unavailable\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+const OK: &str = "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nchart";
+
+#[tokio::test]
+async fn test_reqwest_source_retries() -> anyhow::Result<()> {
+    let base_uri = serve(vec![UNAVAILABLE, UNAVAILABLE, OK]).await?;
+    let eaip = EAIP::new(base_uri.as_str(), "EG", "en-GB").with_source(source(3));
+    assert_eq!(
+        b"chart".to_vec(),
+        eaip.source().get_file(&eaip, "/chart.pdf").await?
+    );
+
+    let base_uri = serve(vec![UNAVAILABLE, UNAVAILABLE]).await?;
+    let eaip = EAIP::new(base_uri.as_str(), "EG", "en-GB").with_source(source(1));
+    let err = eaip
+        .source()
+        .get_file(&eaip, "/chart.pdf")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::EAIPUnavailable(503)));
+    assert!(err.is_transient());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reqwest_source_statuses() -> anyhow::Result<()> {
+    let base_uri = serve(vec![
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        "HTTP/1.1 410 Gone\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        "HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+    ])
+    .await?;
+    let eaip = EAIP::new(base_uri.as_str(), "EG", "en-GB").with_source(source(3));
+    for _ in 0..2 {
+        assert!(matches!(
+            eaip.source().get_file(&eaip, "/chart.pdf").await,
+            Err(Error::EAIPMissingFile(_))
+        ));
+    }
+    let err = eaip
+        .source()
+        .get_file(&eaip, "/chart.pdf")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::EAIPRequestFailed(403)));
+    assert!(!err.is_transient());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_builder_configures_client() -> anyhow::Result<()> {
+    // The user agent is sent with each request.
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let base_uri = format!("http://{}", listener.local_addr()?);
+    let request = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buf = [0u8; 4096];
+        let len = stream.read(&mut buf).await.unwrap();
+        stream.write_all(OK.as_bytes()).await.unwrap();
+        stream.shutdown().await.unwrap();
+        String::from_utf8_lossy(&buf[..len]).to_lowercase()
+    });
+    let eaip = EAIP::builder(base_uri.as_str(), "EG", "en-GB")
+        .user_agent("test-agent/1.0")
+        .build()?;
+    eaip.source().get_file(&eaip, "/chart.pdf").await?;
+    assert!(request.await?.contains("user-agent: test-agent/1.0\r\n"));
+
+    // A server that never responds times out.
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let base_uri = format!("http://{}", listener.local_addr()?);
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        drop(stream);
+    });
+    let eaip = EAIP::builder(base_uri.as_str(), "EG", "en-GB")
+        .timeout(Duration::from_millis(100))
+        .retry_policy(RetryPolicy::none())
+        .build()?;
+    let err = eaip
+        .source()
+        .get_file(&eaip, "/chart.pdf")
+        .await
+        .unwrap_err();
+    assert!(matches!(&err, Error::EAIPFetchError(e) if e.is_timeout()));
+    server.abort();
+    Ok(())
+}
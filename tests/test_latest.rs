@@ -0,0 +1,63 @@
+use airac::AIRAC;
+use eaip::prelude::*;
+
+const NAVAIDS: &str = r#"<html><body><table><tbody>
+<tr>
+  <td><strong>ABERDEEN</strong><p class="line">VOR/DME</p></td>
+  <td>ADN</td>
+  <td>114.300 MHz</td>
+  <td>H24</td>
+  <td>571839.00N 0021602.00W</td>
+  <td>600 FT</td>
+</tr>
+</tbody></table></body></html>"#;
+
+fn source_with(eaip: &EAIP, airacs: &[AIRAC]) -> MemorySource {
+    let mut source = MemorySource::new();
+    for airac in airacs {
+        let toc = Part::EnRoute(ENR::TableOfContents);
+        source.insert_page(eaip, airac.clone(), toc, EAIPType::HTML, "<html></html>");
+        let navaids = Part::EnRoute(ENR::RadioNavAids(1));
+        source.insert_page(eaip, airac.clone(), navaids, EAIPType::HTML, NAVAIDS);
+    }
+    source
+}
+
+#[tokio::test]
+async fn test_from_latest_eaip() -> anyhow::Result<()> {
+    let current = AIRAC::current();
+    let next = current.next();
+    let eaip = EAIP::new("https://example.com", "EG", "en-GB");
+
+    let only_current = eaip
+        .clone()
+        .with_source(source_with(&eaip, std::slice::from_ref(&current)));
+    let navaids = Navaids::from_latest_eaip(&only_current).await?;
+    assert_eq!(&current, navaids.airac());
+    assert!(!navaids.is_pending());
+    assert_eq!(1, navaids.data().len());
+
+    let both = eaip
+        .clone()
+        .with_source(source_with(&eaip, &[current, next.clone()]));
+    let navaids = Navaids::from_latest_eaip(&both).await?;
+    assert_eq!(&next, navaids.airac());
+    assert_eq!(next.starts(), navaids.effective());
+    assert!(navaids.is_pending());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_is_published() -> anyhow::Result<()> {
+    let current = AIRAC::current();
+    let next = current.next();
+    // The next cycle is published early, even though the eAIP has no publishing offset.
+    let eaip = EAIP::new("https://example.com", "EG", "en-GB");
+    let eaip = eaip
+        .clone()
+        .with_source(source_with(&eaip, std::slice::from_ref(&next)));
+    assert!(eaip.is_published(next.clone()).await?);
+    assert!(!eaip.is_published(next.next()).await?);
+    assert_eq!(next, eaip.latest_published_airac().await?);
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use airac::AIRAC;
+use async_trait::async_trait;
+use eaip::prelude::*;
+
+const ENR_TOC: &str = r#"<html><body>
+<a href="EG-ENR-4.1-en-GB.html#ENR-4.1">ENR 4.1</a>
+<a href="https://elsewhere.example.com/page.html">Elsewhere</a>
+</body></html>"#;
+
+const NAVAIDS: &str = r#"<html><body><table><tbody>
+<tr>
+  <td><strong>ABERDEEN</strong><p class="line">VOR/DME</p></td>
+  <td>ADN</td>
+  <td>114.300 MHz</td>
+  <td>H24</td>
+  <td>571839.00N 0021602.00W</td>
+  <td>600 FT</td>
+</tr>
+</tbody></table>
+<a href="../../graphics/eAIP/ENR-4.1-chart.pdf">Chart</a>
+<a href="EG-ENR-0.1-en-GB.html">Back</a>
+</body></html>"#;
+
+#[tokio::test]
+async fn test_mirror() -> anyhow::Result<()> {
+    let dir = std::env::temp_dir().join(format!("eaip-mirror-test-{}", std::process::id()));
+    let airac = AIRAC::from_ymd(2022, 5, 19);
+    let eaip = EAIP::new("https://example.com", "EG", "en-GB");
+
+    let mut source = MemorySource::new();
+    source.insert("/2022-05-19-AIRAC/html/eAIP/EG-ENR-0.1-en-GB.html", ENR_TOC);
+    source.insert("/2022-05-19-AIRAC/html/eAIP/EG-ENR-4.1-en-GB.html", NAVAIDS);
+    source.insert_file(
+        "/2022-05-19-AIRAC/graphics/eAIP/ENR-4.1-chart.pdf",
+        b"%PDF-1.4".to_vec(),
+    );
+    let eaip = eaip.with_source(source);
+
+    let mirror = Mirror::new(&eaip, airac.clone(), &dir)
+        .with_start_pages(vec![Part::EnRoute(ENR::TableOfContents)]);
+    let report = mirror.run().await?;
+    assert_eq!(3, report.downloaded());
+    assert_eq!(0, report.skipped());
+    assert!(report.failed().is_empty());
+
+    let manifest = std::fs::read_to_string(report.manifest())?;
+    assert_eq!(3, manifest.lines().count());
+    assert!(manifest.contains("  graphics/eAIP/ENR-4.1-chart.pdf"));
+
+    let report = mirror.run().await?;
+    assert_eq!(0, report.downloaded());
+    assert_eq!(3, report.skipped());
+
+    let offline =
+        EAIP::new("https://example.com", "EG", "en-GB").with_source(DirectorySource::new(&dir));
+    let navaids = Navaids::from_eaip(&offline, airac).await;
+    std::fs::remove_dir_all(&dir)?;
+    assert_eq!("ADN", navaids?[0].id());
+    Ok(())
+}
+
+/// A page source that fails with a server error for one location.
+#[derive(Debug)]
+struct FailingSource {
+    inner: MemorySource,
+    failing: String,
+}
+
+#[async_trait]
+impl PageSource for FailingSource {
+    async fn get_page(
+        &self,
+        eaip: &EAIP,
+        airac: AIRAC,
+        part: Part,
+        typ: EAIPType,
+    ) -> Result<String> {
+        self.inner.get_page(eaip, airac, part, typ).await
+    }
+
+    async fn get_file(&self, eaip: &EAIP, location: &str) -> Result<Vec<u8>> {
+        if location == self.failing {
+            return Err(Error::EAIPUnavailable(503));
+        }
+        self.inner.get_file(eaip, location).await
+    }
+}
+
+#[tokio::test]
+async fn test_mirror_resume_and_failures() -> anyhow::Result<()> {
+    let dir = std::env::temp_dir().join(format!("eaip-mirror-resume-test-{}", std::process::id()));
+    let airac = AIRAC::from_ymd(2022, 5, 19);
+    let chart = "/2022-05-19-AIRAC/graphics/eAIP/ENR-4.1-chart.pdf";
+
+    let mut source = MemorySource::new();
+    source.insert("/2022-05-19-AIRAC/html/eAIP/EG-ENR-0.1-en-GB.html", ENR_TOC);
+    source.insert("/2022-05-19-AIRAC/html/eAIP/EG-ENR-4.1-en-GB.html", NAVAIDS);
+    source.insert_file(chart, b"%PDF-1.4".to_vec());
+
+    // A file left part-written by an interrupted mirror isn't treated as complete.
+    let part = dir.join("2022-05-19-AIRAC/html/eAIP/EG-ENR-4.1-en-GB.html.part");
+    std::fs::create_dir_all(part.parent().unwrap())?;
+    std::fs::write(&part, "<html><bo")?;
+
+    // A server error for the chart is reported, and the rest of the package is mirrored.
+    let eaip = EAIP::new("https://example.com", "EG", "en-GB").with_source(FailingSource {
+        inner: source,
+        failing: chart.to_string(),
+    });
+    let report = Mirror::new(&eaip, airac, &dir)
+        .with_start_pages(vec![Part::EnRoute(ENR::TableOfContents)])
+        .run()
+        .await;
+    let manifest = match &report {
+        Ok(r) => std::fs::read_to_string(r.manifest()),
+        Err(_) => Ok(String::new()),
+    };
+    std::fs::remove_dir_all(&dir)?;
+
+    let report = report?;
+    assert_eq!(2, report.downloaded());
+    assert_eq!(0, report.skipped());
+    assert_eq!(&vec![chart.to_string()], report.failed());
+    let manifest = manifest?;
+    assert_eq!(2, manifest.lines().count());
+    assert!(!manifest.contains("chart.pdf"));
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use airac::AIRAC;
+use eaip::prelude::*;
+
+const NAVAIDS: &str = r#"<html><body><table><tbody>
+<tr>
+  <td><strong>ABERDEEN</strong><p class="line">VOR/DME</p></td>
+  <td>ADN</td>
+  <td>114.300 MHz</td>
+  <td>H24</td>
+  <td>571839.00N 0021602.00W</td>
+  <td>600 FT</td>
+</tr>
+</tbody></table></body></html>"#;
+
+fn test_eaip() -> EAIP {
+    EAIP::new("https://example.com", "EG", "en-GB")
+}
+
+#[tokio::test]
+async fn test_memory_source() -> anyhow::Result<()> {
+    let airac = AIRAC::from_ymd(2022, 5, 19);
+    let mut source = MemorySource::new();
+    source.insert_page(
+        &test_eaip(),
+        airac.clone(),
+        Part::EnRoute(ENR::RadioNavAids(1)),
+        EAIPType::HTML,
+        NAVAIDS,
+    );
+    let eaip = test_eaip().with_source(source);
+
+    let navaids = Navaids::from_eaip(&eaip, airac.clone()).await?;
+    assert_eq!(1, navaids.len());
+    assert_eq!("ADN", navaids[0].id());
+    assert_eq!(NavAidKind::VORDME, navaids[0].kind());
+    assert_eq!(114300, navaids[0].frequency_khz());
+
+    assert!(matches!(
+        Intersections::from_eaip(&eaip, airac).await,
+        Err(Error::EAIPMissingPage(..))
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_directory_source() -> anyhow::Result<()> {
+    let airac = AIRAC::from_ymd(2022, 5, 19);
+    let root = std::env::temp_dir().join(format!("eaip-test-{}", std::process::id()));
+    let page = root.join("2022-05-19-AIRAC/html/eAIP/EG-ENR-4.1-en-GB.html");
+    std::fs::create_dir_all(page.parent().unwrap())?;
+    std::fs::write(&page, NAVAIDS)?;
+
+    // A directory where a page should be can't be read, but isn't missing.
+    let unreadable = root.join("2022-05-19-AIRAC/html/eAIP/EG-ENR-4.4-en-GB.html");
+    std::fs::create_dir_all(&unreadable)?;
+
+    let eaip = test_eaip().with_source(DirectorySource::new(&root));
+    let navaids = Navaids::from_eaip(&eaip, airac.clone()).await;
+    let intersections = Intersections::from_eaip(&eaip, airac.clone()).await;
+    let airways = Airways::from_eaip(&eaip, airac).await;
+    std::fs::remove_dir_all(&root)?;
+
+    assert_eq!("ABERDEEN", navaids?[0].name());
+    assert!(matches!(intersections, Err(Error::IOError(_))));
+    assert!(matches!(airways, Err(Error::EAIPMissingPage(..))));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_builder() -> anyhow::Result<()> {
+    let airac = AIRAC::from_ymd(2022, 5, 19);
+    let eaip = EAIP::builder("https://example.com", "EH", "en-GB")
+        .publish_offset(chrono::Duration::weeks(2))
+        .user_agent("eaip-tests")
+        .timeout(std::time::Duration::from_secs(5))
+        .build()?;
+    assert_eq!(
+        EAIP::new_with_offset(
+            "https://example.com",
+            "EH",
+            "en-GB",
+            chrono::Duration::weeks(2)
+        ),
+        eaip
+    );
+
+    let mut source = MemorySource::new();
+    source.insert("/2022-05-05-AIRAC/html/eAIP/EH-ENR-4.1-en-GB.html", NAVAIDS);
+    let eaip = EAIP::builder("https://example.com", "EH", "en-GB")
+        .publish_offset(chrono::Duration::weeks(2))
+        .source(source)
+        .build()?;
+    assert_eq!(1, Navaids::from_eaip(&eaip, airac).await?.len());
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use airac::AIRAC;
+use eaip::prelude::*;
+
+/// ENR 5.1 in the style of NATS, with vertical limits in a nested table.
+const NATS: &str = r#"<html><body><table>
+<thead><tr><th>Identification, name and lateral limits</th><th>Upper/lower limit</th><th>Remarks</th></tr></thead>
+<tbody>
+<tr>
+  <td><strong>EG D201 ABERPORTH</strong><br/>520000N 0050000W - 523000N 0043000W - 520000N 0043000W - 520000N 0050000W</td>
+  <td><table><tbody><tr><td class="Upper">FL 500</td></tr><tr><td class="Lower">SFC</td></tr></tbody></table></td>
+  <td>Activity: Mon-Fri 0830-1700; other times by NOTAM.<br/>Nature of hazard: missile firing.</td>
+</tr>
+<tr>
+  <td><strong>EG P111</strong><br/>HYDE PARK<br/>A circle, 0.5 NM radius, centred at 513030N 0000900W</td>
+  <td>2500 FT ALT<br/>SFC</td>
+  <td>H24</td>
+</tr>
+</tbody>
+</table></body></html>"#;
+
+/// ENR 5.1 in the style of LVNL.
+const LVNL: &str = r#"<html><body><table><tbody>
+<tr>
+  <td>EH R8 VLIELAND<br/>531500N 0045500E - 532000N 0050500E - 531000N 0050500E</td>
+  <td>FL 100<br/>GND</td>
+  <td>Active: HJ</td>
+</tr>
+<tr>
+  <td>Activity of EH R8 is published by NOTAM</td>
+  <td></td>
+  <td>See also EH D12</td>
+</tr>
+</tbody></table></body></html>"#;
+
+#[tokio::test]
+async fn test_nats_restricted_areas() -> anyhow::Result<()> {
+    let airac = AIRAC::from_ymd(2022, 5, 19);
+    let eaip = EAIP::new("https://example.com", "EG", "en-GB");
+    let mut source = MemorySource::new();
+    source.insert_page(
+        &eaip,
+        airac.clone(),
+        Part::EnRoute(ENR::NavWarnings(1)),
+        EAIPType::HTML,
+        NATS,
+    );
+    let eaip = eaip.with_source(source);
+
+    let areas = RestrictedAreas::from_eaip(&eaip, airac).await?;
+    assert_eq!(2, areas.len());
+
+    let d201 = &areas[0];
+    assert_eq!("EG D201", d201.designator());
+    assert_eq!("ABERPORTH", d201.name());
+    assert_eq!(RestrictedAreaKind::Danger, d201.kind());
+    assert_eq!("FL 500", d201.upper_limit());
+    assert_eq!("SFC", d201.lower_limit());
+    assert_eq!("Mon-Fri 0830-1700; other times by NOTAM.", d201.activity());
+    assert!(d201
+        .remarks()
+        .ends_with("Nature of hazard: missile firing."));
+    assert_eq!(4, d201.boundary().len());
+    assert_eq!((52.0, -5.0), d201.boundary()[0]);
+    assert!(d201.unparsed_limits().is_empty());
+
+    let p111 = &areas[1];
+    assert_eq!("EG P111", p111.designator());
+    assert_eq!("HYDE PARK", p111.name());
+    assert_eq!(RestrictedAreaKind::Prohibited, p111.kind());
+    assert_eq!("2500 FT ALT", p111.upper_limit());
+    assert_eq!("SFC", p111.lower_limit());
+    assert_eq!("", p111.activity());
+    assert_eq!("H24", p111.remarks());
+    assert!(p111.boundary().len() > 4);
+    assert!(p111.unparsed_limits().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_lvnl_restricted_areas() -> anyhow::Result<()> {
+    let areas = RestrictedAreas::parse(LVNL)?;
+    // Designators within remarks don't start another area.
+    assert_eq!(1, areas.len());
+    assert_eq!("EH R8", areas[0].designator());
+    assert_eq!("VLIELAND", areas[0].name());
+    assert_eq!(RestrictedAreaKind::Restricted, areas[0].kind());
+    assert_eq!("FL 100", areas[0].upper_limit());
+    assert_eq!("GND", areas[0].lower_limit());
+    assert_eq!("HJ", areas[0].activity());
+    assert_eq!(3, areas[0].boundary().len());
+    Ok(())
+}
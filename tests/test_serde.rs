@@ -0,0 +1,49 @@
+#![cfg(feature = "serde")]
+
+use airac::AIRAC;
+use eaip::prelude::*;
+
+const NAVAIDS: &str = r#"<html><body><table><tbody>
+<tr>
+  <td><strong>ABERDEEN</strong><p class="line">VOR/DME</p></td>
+  <td>ADN</td>
+  <td>114.300 MHz</td>
+  <td>H24</td>
+  <td>571839.00N 0021602.00W</td>
+  <td>600 FT</td>
+</tr>
+</tbody></table></body></html>"#;
+
+#[test]
+fn test_navaids_round_trip() -> anyhow::Result<()> {
+    let navaids = Navaids::parse(NAVAIDS)?;
+    let json = serde_json::to_string(&navaids)?;
+    assert!(json.contains(r#""id":"ADN""#));
+    assert!(json.contains(r#""kind":"VORDME""#));
+    assert!(json.contains(r#""frequency_khz":114300"#));
+
+    let reloaded: Navaids = serde_json::from_str(&json)?;
+    assert_eq!(navaids, reloaded);
+    Ok(())
+}
+
+#[test]
+fn test_parts_round_trip() -> anyhow::Result<()> {
+    let part = Part::Aerodromes(AD::Aerodromes("EGBO".to_string()));
+    let json = serde_json::to_string(&(part.clone(), EAIPType::HTML))?;
+    assert_eq!(r#"[{"Aerodromes":{"Aerodromes":"EGBO"}},"HTML"]"#, json);
+    let reloaded: (Part, EAIPType) = serde_json::from_str(&json)?;
+    assert_eq!((part, EAIPType::HTML), reloaded);
+    Ok(())
+}
+
+#[test]
+fn test_airac_as_date() -> anyhow::Result<()> {
+    let history = r#"<table><tr><td>19 MAY 2022</td><td><a href="2022-05-19-AIRAC/html/index-en-GB.html">AMDT</a></td></tr></table>"#;
+    let cycles = Cycles::parse(history)?;
+    let json = serde_json::to_string(&cycles)?;
+    assert!(json.contains(r#""airac":"2022-05-19""#));
+    let reloaded: Cycles = serde_json::from_str(&json)?;
+    assert_eq!(&AIRAC::from_ymd(2022, 5, 19), reloaded[0].airac());
+    Ok(())
+}